@@ -0,0 +1,16 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+
+use crate::behaviour::entity::comparison::function::glob_match;
+
+/// Compares the string lhs with the array of strings rhs.
+pub type StringArrayComparisonFunction = fn(String, Vec<String>) -> bool;
+
+/// True if lhs matches at least one of the wildcard patterns in rhs.
+pub const FN_GLOB_MATCH_ANY: StringArrayComparisonFunction = |lhs, rhs| rhs.iter().any(|pattern| glob_match(lhs.as_str(), pattern.as_str()));
+
+lazy_static! {
+    pub static ref STRING_ARRAY_COMPARISONS: HashMap<&'static str, StringArrayComparisonFunction> =
+        vec![("glob_match_any", FN_GLOB_MATCH_ANY)].into_iter().collect();
+}
@@ -0,0 +1,8 @@
+pub use function::StringArrayComparisonFunction;
+pub use function::STRING_ARRAY_COMPARISONS;
+pub use string_array_comparison::StringArrayComparison;
+pub use string_array_comparison_properties::StringArrayComparisonProperties;
+
+pub mod function;
+pub mod string_array_comparison;
+pub mod string_array_comparison_properties;
@@ -0,0 +1,217 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::array_comparison::string_array_comparison_properties::StringArrayComparisonProperties;
+use crate::behaviour::entity::array_comparison::StringArrayComparisonFunction;
+use crate::behaviour::entity::comparison::function::prepare_glob;
+use crate::behaviour::entity::comparison::string_comparison::{to_bool, to_string};
+use crate::behaviour::entity::comparison::StringComparisonProperties;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::gate::Gate;
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone)]
+pub enum StringArrayComparisonPosition {
+    LHS,
+    RHS,
+    CASE_SENSITIVE,
+    NORMALIZE,
+}
+
+pub type StringArrayComparisonExpressionValue = (StringArrayComparisonPosition, Value);
+
+/// The state of the inputs of a string array comparison.
+#[derive(Debug, Clone)]
+pub struct StringArrayComparisonExpression {
+    pub lhs: String,
+    pub rhs: Vec<String>,
+    pub case_sensitive: bool,
+    pub normalize: bool,
+}
+
+impl StringArrayComparisonExpression {
+    /// Initializes the expression with the current values of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance) -> Self {
+        let get = |property: StringArrayComparisonProperties| e.get(property.as_ref()).unwrap_or_else(|| property.default_value());
+        StringArrayComparisonExpression {
+            lhs: to_string(&get(StringArrayComparisonProperties::LHS)),
+            rhs: to_strings(&get(StringArrayComparisonProperties::RHS)),
+            case_sensitive: to_bool(&get(StringArrayComparisonProperties::CASE_SENSITIVE), StringComparisonProperties::CASE_SENSITIVE),
+            normalize: to_bool(&get(StringArrayComparisonProperties::NORMALIZE), StringComparisonProperties::NORMALIZE),
+        }
+    }
+
+    pub fn set(self, position: StringArrayComparisonPosition, value: &Value) -> Self {
+        match position {
+            StringArrayComparisonPosition::LHS => StringArrayComparisonExpression { lhs: to_string(value), ..self },
+            StringArrayComparisonPosition::RHS => StringArrayComparisonExpression {
+                rhs: to_strings(value),
+                ..self
+            },
+            StringArrayComparisonPosition::CASE_SENSITIVE => StringArrayComparisonExpression {
+                case_sensitive: to_bool(value, StringComparisonProperties::CASE_SENSITIVE),
+                ..self
+            },
+            StringArrayComparisonPosition::NORMALIZE => StringArrayComparisonExpression {
+                normalize: to_bool(value, StringComparisonProperties::NORMALIZE),
+                ..self
+            },
+        }
+    }
+}
+
+/// Elements which are not strings are ignored. A single string is treated as an array with one element.
+fn to_strings(value: &Value) -> Vec<String> {
+    match value {
+        Value::Array(elements) => elements.iter().filter_map(|element| element.as_str().map(String::from)).collect(),
+        Value::String(element) => vec![element.clone()],
+        _ => Vec::new(),
+    }
+}
+
+/// Generic implementation of comparisons with a string input (LHS), an array input (RHS) and one result.
+///
+/// Elements of rhs which are not strings are ignored. The inputs CASE_SENSITIVE and NORMALIZE control how lhs and the
+/// elements of rhs are compared. Entity instances which don't provide them are compared case sensitive and without
+/// normalization. The elements of rhs are wildcard patterns, so lhs and the patterns are prepared using `prepare_glob`.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringArrayComparison<'a> {
+    pub lhs: RwLock<Stream<'a, StringArrayComparisonExpressionValue>>,
+
+    pub rhs: RwLock<Stream<'a, StringArrayComparisonExpressionValue>>,
+
+    pub case_sensitive: Option<RwLock<Stream<'a, StringArrayComparisonExpressionValue>>>,
+
+    pub normalize: Option<RwLock<Stream<'a, StringArrayComparisonExpressionValue>>>,
+
+    pub f: StringArrayComparisonFunction,
+
+    pub internal_result: RwLock<Stream<'a, bool>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringArrayComparison<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringArrayComparisonFunction) -> StringArrayComparison<'static> {
+        let lhs = e
+            .properties
+            .get(StringArrayComparisonProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringArrayComparisonExpressionValue { (StringArrayComparisonPosition::LHS, v.clone()) });
+        let rhs = e
+            .properties
+            .get(StringArrayComparisonProperties::RHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringArrayComparisonExpressionValue { (StringArrayComparisonPosition::RHS, v.clone()) });
+
+        let case_sensitive = e.properties.get(StringArrayComparisonProperties::CASE_SENSITIVE.as_ref()).map(|property| {
+            property
+                .stream
+                .read()
+                .unwrap()
+                .map(|v| -> StringArrayComparisonExpressionValue { (StringArrayComparisonPosition::CASE_SENSITIVE, v.clone()) })
+        });
+        let normalize = e.properties.get(StringArrayComparisonProperties::NORMALIZE.as_ref()).map(|property| {
+            property
+                .stream
+                .read()
+                .unwrap()
+                .map(|v| -> StringArrayComparisonExpressionValue { (StringArrayComparisonPosition::NORMALIZE, v.clone()) })
+        });
+
+        let mut inputs = lhs.merge(&rhs);
+        if let Some(case_sensitive) = &case_sensitive {
+            inputs = inputs.merge(case_sensitive);
+        }
+        if let Some(normalize) = &normalize {
+            inputs = inputs.merge(normalize);
+        }
+        let expression = inputs.fold(StringArrayComparisonExpression::new(&e), |old_state, (o, value)| old_state.set(*o, value));
+
+        // The internal result
+        let internal_result = expression.map(move |e| {
+            let patterns = e
+                .rhs
+                .iter()
+                .map(|pattern| prepare_glob(pattern.as_str(), e.case_sensitive, e.normalize))
+                .collect();
+            f(prepare_glob(e.lhs.as_str(), e.case_sensitive, e.normalize), patterns)
+        });
+
+        let handle_id = e.properties.get(StringArrayComparisonProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_array_comparison = StringArrayComparison {
+            lhs: RwLock::new(lhs),
+            rhs: RwLock::new(rhs),
+            case_sensitive: case_sensitive.map(RwLock::new),
+            normalize: normalize.map(RwLock::new),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_array_comparison.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string array comparison: {}", v);
+                e.set(StringArrayComparisonProperties::RESULT.to_string(), json!(*v));
+            },
+            handle_id,
+        );
+
+        string_array_comparison
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringArrayComparison<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string array comparison {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringArrayComparison<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringArrayComparisonProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringArrayComparisonProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+impl Gate for StringArrayComparison<'_> {
+    fn rhs(&self, value: Value) {
+        self.entity.set(StringArrayComparisonProperties::RHS.as_ref(), value);
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringArrayComparison<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string array comparison");
+        self.disconnect();
+    }
+}
@@ -0,0 +1,58 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringArrayComparisonProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "rhs")]
+    RHS,
+    /// If false, lhs and the elements of rhs are compared using full unicode case folding
+    #[strum(serialize = "case_sensitive")]
+    CASE_SENSITIVE,
+    /// If true, lhs and the elements of rhs are compared in the unicode normalization form NFC
+    #[strum(serialize = "normalize")]
+    NORMALIZE,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringArrayComparisonProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringArrayComparisonProperties::LHS => json!(""),
+            StringArrayComparisonProperties::RHS => json!([]),
+            StringArrayComparisonProperties::CASE_SENSITIVE => json!(true),
+            StringArrayComparisonProperties::NORMALIZE => json!(false),
+            StringArrayComparisonProperties::RESULT => json!(false),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringArrayComparisonProperties::LHS),
+            NamedProperty::from(StringArrayComparisonProperties::RHS),
+            NamedProperty::from(StringArrayComparisonProperties::CASE_SENSITIVE),
+            NamedProperty::from(StringArrayComparisonProperties::NORMALIZE),
+            NamedProperty::from(StringArrayComparisonProperties::RESULT),
+        ]
+    }
+}
+
+impl From<StringArrayComparisonProperties> for NamedProperty {
+    fn from(p: StringArrayComparisonProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringArrayComparisonProperties> for String {
+    fn from(p: StringArrayComparisonProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -0,0 +1,16 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+pub type StringArrayGateFunction = fn(String, String) -> Vec<String>;
+
+/// Splits lhs by the separator rhs. An empty separator doesn't split at all.
+pub const FN_SPLIT: StringArrayGateFunction = |lhs, rhs| {
+    if rhs.is_empty() {
+        return vec![lhs];
+    }
+    lhs.split(rhs.as_str()).map(String::from).collect()
+};
+
+lazy_static! {
+    pub static ref STRING_ARRAY_GATES: HashMap<&'static str, StringArrayGateFunction> = vec![("split", FN_SPLIT)].into_iter().collect();
+}
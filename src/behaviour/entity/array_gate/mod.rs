@@ -0,0 +1,8 @@
+pub use function::StringArrayGateFunction;
+pub use function::STRING_ARRAY_GATES;
+pub use string_array_gate::StringArrayGate;
+pub use string_array_gate_properties::StringArrayGateProperties;
+
+pub mod function;
+pub mod string_array_gate;
+pub mod string_array_gate_properties;
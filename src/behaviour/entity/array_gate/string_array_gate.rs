@@ -0,0 +1,131 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::array_gate::function::StringArrayGateFunction;
+use crate::behaviour::entity::array_gate::string_array_gate_properties::StringArrayGateProperties;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::expression::{Expression, ExpressionValue, OperatorPosition};
+use crate::reactive::entity::gate::Gate;
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+pub type StringArrayExpressionValue = ExpressionValue<String>;
+
+/// Generic implementation of string gates with two string inputs (LHS,RHS) and an array of strings as result.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringArrayGate<'a> {
+    pub lhs: RwLock<Stream<'a, StringArrayExpressionValue>>,
+
+    pub rhs: RwLock<Stream<'a, StringArrayExpressionValue>>,
+
+    pub f: StringArrayGateFunction,
+
+    pub internal_result: RwLock<Stream<'a, Vec<String>>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringArrayGate<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringArrayGateFunction) -> StringArrayGate<'static> {
+        let lhs = e
+            .properties
+            .get(StringArrayGateProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| match v.as_str() {
+                Some(lhs_str) => (OperatorPosition::LHS, String::from(lhs_str)),
+                None => (OperatorPosition::LHS, String::new()),
+            });
+        let rhs = e
+            .properties
+            .get(StringArrayGateProperties::RHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringArrayExpressionValue {
+                match v.as_str() {
+                    Some(rhs_str) => (OperatorPosition::RHS, String::from(rhs_str)),
+                    None => (OperatorPosition::RHS, String::new()),
+                }
+            });
+
+        let expression = lhs
+            .merge(&rhs)
+            .fold(Expression::new(String::new(), String::new()), |old_state, (o, value)| match *o {
+                OperatorPosition::LHS => old_state.lhs(value.clone()),
+                OperatorPosition::RHS => old_state.rhs(value.clone()),
+            });
+
+        // The internal result
+        let internal_result = expression.map(move |e| f(e.lhs.clone(), e.rhs.clone()));
+
+        let handle_id = e.properties.get(StringArrayGateProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_array_gate = StringArrayGate {
+            lhs: RwLock::new(lhs),
+            rhs: RwLock::new(rhs),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_array_gate.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string array gate: {:?}", v);
+                e.set(StringArrayGateProperties::RESULT.to_string(), json!(*v));
+            },
+            handle_id,
+        );
+
+        string_array_gate
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringArrayGate<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string array gate {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringArrayGate<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringArrayGateProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringArrayGateProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+impl Gate for StringArrayGate<'_> {
+    fn rhs(&self, value: Value) {
+        self.entity.set(StringArrayGateProperties::RHS.as_ref(), value);
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringArrayGate<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string array gate");
+        self.disconnect();
+    }
+}
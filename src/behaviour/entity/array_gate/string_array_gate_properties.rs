@@ -0,0 +1,48 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringArrayGateProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "rhs")]
+    RHS,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringArrayGateProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringArrayGateProperties::LHS => json!(""),
+            StringArrayGateProperties::RHS => json!(""),
+            StringArrayGateProperties::RESULT => json!([]),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringArrayGateProperties::LHS),
+            NamedProperty::from(StringArrayGateProperties::RHS),
+            NamedProperty::from(StringArrayGateProperties::RESULT),
+        ]
+    }
+}
+
+impl From<StringArrayGateProperties> for NamedProperty {
+    fn from(p: StringArrayGateProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringArrayGateProperties> for String {
+    fn from(p: StringArrayGateProperties) -> Self {
+        p.to_string()
+    }
+}
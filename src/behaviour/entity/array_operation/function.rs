@@ -0,0 +1,15 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+pub type StringArrayOperationFunction = fn(String) -> Vec<String>;
+
+pub const FN_LINES: StringArrayOperationFunction = |lhs: String| lhs.lines().map(String::from).collect();
+pub const FN_CHARS: StringArrayOperationFunction = |lhs: String| lhs.chars().map(String::from).collect();
+pub const FN_SPLIT_WHITESPACE: StringArrayOperationFunction = |lhs: String| lhs.split_whitespace().map(String::from).collect();
+
+lazy_static! {
+    pub static ref STRING_ARRAY_OPERATIONS: HashMap<&'static str, StringArrayOperationFunction> =
+        vec![("lines", FN_LINES), ("chars", FN_CHARS), ("split_whitespace", FN_SPLIT_WHITESPACE),]
+            .into_iter()
+            .collect();
+}
@@ -0,0 +1,8 @@
+pub use function::StringArrayOperationFunction;
+pub use function::STRING_ARRAY_OPERATIONS;
+pub use string_array_operation::StringArrayOperation;
+pub use string_array_operation_properties::StringArrayOperationProperties;
+
+pub mod function;
+pub mod string_array_operation;
+pub mod string_array_operation_properties;
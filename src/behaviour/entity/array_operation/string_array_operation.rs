@@ -0,0 +1,92 @@
+use std::convert::AsRef;
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::array_operation::string_array_operation_properties::StringArrayOperationProperties;
+use crate::behaviour::entity::array_operation::StringArrayOperationFunction;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+/// Generic implementation of string operations with one string input and an array of strings as result.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringArrayOperation<'a> {
+    pub f: StringArrayOperationFunction,
+
+    pub internal_result: RwLock<Stream<'a, Value>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringArrayOperation<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringArrayOperationFunction) -> StringArrayOperation<'static> {
+        let handle_id = e.properties.get(StringArrayOperationProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let internal_result = e
+            .properties
+            .get(StringArrayOperationProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(move |v| match v.as_str() {
+                Some(lhs_str) => json!(f(String::from(lhs_str))),
+                None => StringArrayOperationProperties::RESULT.default_value(),
+            });
+        let string_array_operation = StringArrayOperation {
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_array_operation.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string array operation: {}", v);
+                e.set(StringArrayOperationProperties::RESULT.to_string(), v.clone());
+            },
+            handle_id,
+        );
+
+        string_array_operation
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringArrayOperation<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string array operation {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringArrayOperation<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringArrayOperationProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringArrayOperationProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringArrayOperation<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string array operation");
+        self.disconnect();
+    }
+}
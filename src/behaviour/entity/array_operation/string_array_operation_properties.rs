@@ -0,0 +1,44 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringArrayOperationProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringArrayOperationProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringArrayOperationProperties::LHS => json!(""),
+            StringArrayOperationProperties::RESULT => json!([]),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringArrayOperationProperties::LHS),
+            NamedProperty::from(StringArrayOperationProperties::RESULT),
+        ]
+    }
+}
+
+impl From<StringArrayOperationProperties> for NamedProperty {
+    fn from(p: StringArrayOperationProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringArrayOperationProperties> for String {
+    fn from(p: StringArrayOperationProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -1,15 +1,74 @@
+use caseless::default_case_fold_str;
+use glob::Pattern;
 use lazy_static::lazy_static;
 use std::collections::HashMap;
+use unicode_normalization::UnicodeNormalization;
 
 pub type StringComparisonFunction = fn(String, String) -> bool;
 
 pub const FN_STARTS_WITH: StringComparisonFunction = |lhs, rhs| lhs.starts_with(rhs.as_str());
 pub const FN_ENDS_WITH: StringComparisonFunction = |lhs, rhs| lhs.ends_with(rhs.as_str());
 pub const FN_CONTAINS: StringComparisonFunction = |lhs, rhs| lhs.contains(rhs.as_str());
+pub const FN_EQUALS: StringComparisonFunction = |lhs, rhs| lhs == rhs;
+pub const FN_NOT_EQUALS: StringComparisonFunction = |lhs, rhs| lhs != rhs;
+// Lexicographic order by unicode code points
+pub const FN_LESS_THAN: StringComparisonFunction = |lhs, rhs| lhs < rhs;
+pub const FN_GREATER_THAN: StringComparisonFunction = |lhs, rhs| lhs > rhs;
+/// True if lhs matches the wildcard pattern rhs (*, ?, [a-z], [!a-z]). Invalid patterns don't match anything. The
+/// operands are prepared using `prepare_glob`.
+pub const FN_GLOB_MATCH: StringComparisonFunction = |lhs, rhs| glob_match(lhs.as_str(), rhs.as_str());
+
+/// The comparisons which match wildcard patterns.
+pub const GLOB_COMPARISONS: [&str; 1] = ["glob_match"];
 
 lazy_static! {
-    pub static ref STRING_COMPARISONS: HashMap<&'static str, StringComparisonFunction> =
-        vec![("starts_with", FN_STARTS_WITH), ("ends_with", FN_ENDS_WITH), ("contains", FN_CONTAINS),]
-            .into_iter()
-            .collect();
+    pub static ref STRING_COMPARISONS: HashMap<&'static str, StringComparisonFunction> = vec![
+        ("starts_with", FN_STARTS_WITH),
+        ("ends_with", FN_ENDS_WITH),
+        ("contains", FN_CONTAINS),
+        ("equals", FN_EQUALS),
+        ("not_equals", FN_NOT_EQUALS),
+        ("less_than", FN_LESS_THAN),
+        ("greater_than", FN_GREATER_THAN),
+        ("glob_match", FN_GLOB_MATCH),
+    ]
+    .into_iter()
+    .collect();
+}
+
+/// Matches the text against the wildcard pattern. Unlike file globs, * also matches path separators.
+pub fn glob_match(text: &str, pattern: &str) -> bool {
+    Pattern::new(pattern).map(|pattern| pattern.matches(text)).unwrap_or(false)
+}
+
+/// Prepares an operand according to the comparison mode. The string is decomposed before case folding, so that
+/// characters which are only equal in a normalized form are folded consistently.
+pub fn prepare(s: &str, case_sensitive: bool, normalize: bool) -> String {
+    let s = if normalize { s.nfd().collect() } else { String::from(s) };
+    let s = if case_sensitive { s } else { default_case_fold_str(s.as_str()) };
+    if normalize {
+        s.nfc().collect()
+    } else {
+        s
+    }
+}
+
+/// Prepares the text or the pattern of a wildcard match. Full case folding changes the number of characters ("ß"
+/// becomes "ss"), which would shift `?` and `[...]` against the text. Instead, each character is lowercased on its
+/// own and characters without a single character lowercase mapping are kept.
+pub fn prepare_glob(s: &str, case_sensitive: bool, normalize: bool) -> String {
+    let s = if normalize { s.nfc().collect() } else { String::from(s) };
+    if case_sensitive {
+        s
+    } else {
+        s.chars().map(simple_lowercase).collect()
+    }
+}
+
+fn simple_lowercase(c: char) -> char {
+    let mut lowercase = c.to_lowercase();
+    match (lowercase.next(), lowercase.next()) {
+        (Some(lowercase), None) => lowercase,
+        _ => c,
+    }
 }
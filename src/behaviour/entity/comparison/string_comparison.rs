@@ -3,25 +3,88 @@ use std::sync::{Arc, RwLock};
 use log::debug;
 use serde_json::{json, Value};
 
+use crate::behaviour::entity::comparison::function::{prepare, prepare_glob, GLOB_COMPARISONS};
 use crate::behaviour::entity::comparison::string_comparison_properties::StringComparisonProperties;
 use crate::behaviour::entity::comparison::StringComparisonFunction;
 use crate::frp::Stream;
 use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
-use crate::reactive::entity::expression::{Expression, ExpressionValue, OperatorPosition};
 use crate::reactive::entity::gate::Gate;
 use crate::reactive::entity::operation::Operation;
 use crate::reactive::entity::Disconnectable;
 
-pub type StringComparisonExpressionValue = ExpressionValue<String>;
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone)]
+pub enum StringComparisonPosition {
+    LHS,
+    RHS,
+    CASE_SENSITIVE,
+    NORMALIZE,
+}
+
+pub type StringComparisonExpressionValue = (StringComparisonPosition, Value);
+
+/// The state of the inputs of a string comparison.
+#[derive(Debug, Clone)]
+pub struct StringComparisonExpression {
+    pub lhs: String,
+    pub rhs: String,
+    pub case_sensitive: bool,
+    pub normalize: bool,
+}
+
+impl StringComparisonExpression {
+    /// Initializes the expression with the current values of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance) -> Self {
+        let get = |property: StringComparisonProperties| e.get(property.as_ref()).unwrap_or_else(|| property.default_value());
+        StringComparisonExpression {
+            lhs: to_string(&get(StringComparisonProperties::LHS)),
+            rhs: to_string(&get(StringComparisonProperties::RHS)),
+            case_sensitive: to_bool(&get(StringComparisonProperties::CASE_SENSITIVE), StringComparisonProperties::CASE_SENSITIVE),
+            normalize: to_bool(&get(StringComparisonProperties::NORMALIZE), StringComparisonProperties::NORMALIZE),
+        }
+    }
+
+    pub fn set(self, position: StringComparisonPosition, value: &Value) -> Self {
+        match position {
+            StringComparisonPosition::LHS => StringComparisonExpression { lhs: to_string(value), ..self },
+            StringComparisonPosition::RHS => StringComparisonExpression { rhs: to_string(value), ..self },
+            StringComparisonPosition::CASE_SENSITIVE => StringComparisonExpression {
+                case_sensitive: to_bool(value, StringComparisonProperties::CASE_SENSITIVE),
+                ..self
+            },
+            StringComparisonPosition::NORMALIZE => StringComparisonExpression {
+                normalize: to_bool(value, StringComparisonProperties::NORMALIZE),
+                ..self
+            },
+        }
+    }
+}
+
+pub fn to_string(value: &Value) -> String {
+    value.as_str().map(String::from).unwrap_or_default()
+}
+
+/// Values which are not a bool are treated as the default value of the property.
+pub fn to_bool(value: &Value, property: StringComparisonProperties) -> bool {
+    value.as_bool().or_else(|| property.default_value().as_bool()).unwrap_or_default()
+}
 
 /// Generic implementation of comparison_gates operations with two inputs (LHS,RHS) and one result.
 ///
+/// The inputs CASE_SENSITIVE and NORMALIZE control how lhs and rhs are compared. Entity instances which don't provide
+/// them are compared case sensitive and without normalization. Wildcard matches lowercase each character instead of
+/// using full case folding.
+///
 /// The implementation is realized using reactive streams.
 pub struct StringComparison<'a> {
     pub lhs: RwLock<Stream<'a, StringComparisonExpressionValue>>,
 
     pub rhs: RwLock<Stream<'a, StringComparisonExpressionValue>>,
 
+    pub case_sensitive: Option<RwLock<Stream<'a, StringComparisonExpressionValue>>>,
+
+    pub normalize: Option<RwLock<Stream<'a, StringComparisonExpressionValue>>>,
+
     pub f: StringComparisonFunction,
 
     pub internal_result: RwLock<Stream<'a, bool>>,
@@ -40,10 +103,7 @@ impl StringComparison<'_> {
             .stream
             .read()
             .unwrap()
-            .map(|v| match v.as_str() {
-                Some(lhs_str) => (OperatorPosition::LHS, String::from(lhs_str)),
-                None => (OperatorPosition::LHS, StringComparisonProperties::LHS.default_value()),
-            });
+            .map(|v| -> StringComparisonExpressionValue { (StringComparisonPosition::LHS, v.clone()) });
         let rhs = e
             .properties
             .get(StringComparisonProperties::RHS.as_ref())
@@ -51,29 +111,47 @@ impl StringComparison<'_> {
             .stream
             .read()
             .unwrap()
-            .map(|v| -> StringComparisonExpressionValue {
-                match v.as_str() {
-                    Some(rhs_str) => (OperatorPosition::RHS, String::from(rhs_str)),
-                    None => (OperatorPosition::RHS, StringComparisonProperties::RHS.default_value()),
-                }
-            });
-
-        let expression = lhs.merge(&rhs).fold(
-            Expression::new(StringComparisonProperties::LHS.default_value(), StringComparisonProperties::RHS.default_value()),
-            |old_state, (o, value)| match *o {
-                OperatorPosition::LHS => old_state.lhs(String::from(value.clone())),
-                OperatorPosition::RHS => old_state.rhs(String::from(value.clone())),
-            },
-        );
+            .map(|v| -> StringComparisonExpressionValue { (StringComparisonPosition::RHS, v.clone()) });
+        let case_sensitive = e.properties.get(StringComparisonProperties::CASE_SENSITIVE.as_ref()).map(|property| {
+            property
+                .stream
+                .read()
+                .unwrap()
+                .map(|v| -> StringComparisonExpressionValue { (StringComparisonPosition::CASE_SENSITIVE, v.clone()) })
+        });
+        let normalize = e.properties.get(StringComparisonProperties::NORMALIZE.as_ref()).map(|property| {
+            property
+                .stream
+                .read()
+                .unwrap()
+                .map(|v| -> StringComparisonExpressionValue { (StringComparisonPosition::NORMALIZE, v.clone()) })
+        });
+
+        let mut inputs = lhs.merge(&rhs);
+        if let Some(case_sensitive) = &case_sensitive {
+            inputs = inputs.merge(case_sensitive);
+        }
+        if let Some(normalize) = &normalize {
+            inputs = inputs.merge(normalize);
+        }
+        let expression = inputs.fold(StringComparisonExpression::new(&e), |old_state, (o, value)| old_state.set(*o, value));
 
         // The internal result
-        let internal_result = expression.map(move |e| f(e.lhs.clone(), e.rhs.clone()));
+        let prepare: fn(&str, bool, bool) -> String = if GLOB_COMPARISONS.contains(&e.type_name.as_str()) {
+            prepare_glob
+        } else {
+            prepare
+        };
+        let internal_result =
+            expression.map(move |e| f(prepare(e.lhs.as_str(), e.case_sensitive, e.normalize), prepare(e.rhs.as_str(), e.case_sensitive, e.normalize)));
 
         let handle_id = e.properties.get(StringComparisonProperties::RESULT.as_ref()).unwrap().id.as_u128();
 
         let string_comparison = StringComparison {
             lhs: RwLock::new(lhs),
             rhs: RwLock::new(rhs),
+            case_sensitive: case_sensitive.map(RwLock::new),
+            normalize: normalize.map(RwLock::new),
             f,
             internal_result: RwLock::new(internal_result),
             entity: e.clone(),
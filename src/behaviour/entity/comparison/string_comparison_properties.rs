@@ -1,5 +1,5 @@
 use indradb::{Identifier, NamedProperty};
-use serde_json::json;
+use serde_json::{json, Value};
 use strum_macros::{AsRefStr, Display, IntoStaticStr};
 
 use crate::reactive::property::NamedProperties;
@@ -11,22 +11,32 @@ pub enum StringComparisonProperties {
     LHS,
     #[strum(serialize = "rhs")]
     RHS,
+    /// If false, lhs and rhs are compared using full unicode case folding
+    #[strum(serialize = "case_sensitive")]
+    CASE_SENSITIVE,
+    /// If true, lhs and rhs are compared in the unicode normalization form NFC
+    #[strum(serialize = "normalize")]
+    NORMALIZE,
     #[strum(serialize = "result")]
     RESULT,
 }
 
 impl StringComparisonProperties {
-    pub fn default_value(&self) -> String {
+    pub fn default_value(&self) -> Value {
         match self {
-            StringComparisonProperties::LHS => String::new(),
-            StringComparisonProperties::RHS => String::new(),
-            StringComparisonProperties::RESULT => String::new(),
+            StringComparisonProperties::LHS => json!(""),
+            StringComparisonProperties::RHS => json!(""),
+            StringComparisonProperties::CASE_SENSITIVE => json!(true),
+            StringComparisonProperties::NORMALIZE => json!(false),
+            StringComparisonProperties::RESULT => json!(false),
         }
     }
     pub fn properties() -> NamedProperties {
         vec![
             NamedProperty::from(StringComparisonProperties::LHS),
             NamedProperty::from(StringComparisonProperties::RHS),
+            NamedProperty::from(StringComparisonProperties::CASE_SENSITIVE),
+            NamedProperty::from(StringComparisonProperties::NORMALIZE),
             NamedProperty::from(StringComparisonProperties::RESULT),
         ]
     }
@@ -36,7 +46,7 @@ impl From<StringComparisonProperties> for NamedProperty {
     fn from(p: StringComparisonProperties) -> Self {
         NamedProperty {
             name: Identifier::new(p.to_string()).unwrap(),
-            value: json!(p.default_value()),
+            value: p.default_value(),
         }
     }
 }
@@ -4,12 +4,60 @@ use async_trait::async_trait;
 use log::debug;
 use uuid::Uuid;
 
+use crate::behaviour::entity::array_comparison::StringArrayComparison;
+use crate::behaviour::entity::array_comparison::STRING_ARRAY_COMPARISONS;
+use crate::behaviour::entity::array_gate::StringArrayGate;
+use crate::behaviour::entity::array_gate::STRING_ARRAY_GATES;
+use crate::behaviour::entity::array_operation::StringArrayOperation;
+use crate::behaviour::entity::array_operation::STRING_ARRAY_OPERATIONS;
 use crate::behaviour::entity::comparison::StringComparison;
 use crate::behaviour::entity::comparison::STRING_COMPARISONS;
+use crate::behaviour::entity::fallible_operation::StringFallibleOperation;
+use crate::behaviour::entity::fallible_operation::STRING_FALLIBLE_OPERATIONS;
 use crate::behaviour::entity::gate::StringGate;
 use crate::behaviour::entity::gate::STRING_GATES;
+use crate::behaviour::entity::hash::StringHash;
+use crate::behaviour::entity::hash::STRING_HASHES;
+use crate::behaviour::entity::insert::StringInsert;
+use crate::behaviour::entity::insert::STRING_INSERTS;
+use crate::behaviour::entity::join::StringJoin;
+use crate::behaviour::entity::join::STRING_JOINS;
+use crate::behaviour::entity::number_format::StringNumberFormat;
+use crate::behaviour::entity::number_format::STRING_NUMBER_FORMATS;
+use crate::behaviour::entity::number_gate::StringNumberGate;
+use crate::behaviour::entity::number_gate::STRING_NUMBER_GATES;
+use crate::behaviour::entity::number_operation::StringNumberOperation;
+use crate::behaviour::entity::number_operation::STRING_NUMBER_OPERATIONS;
 use crate::behaviour::entity::operation::StringOperation;
 use crate::behaviour::entity::operation::STRING_OPERATIONS;
+use crate::behaviour::entity::pad::StringPad;
+use crate::behaviour::entity::pad::STRING_PADS;
+use crate::behaviour::entity::parse::StringParse;
+use crate::behaviour::entity::parse::STRING_PARSES;
+use crate::behaviour::entity::predicate::StringPredicate;
+use crate::behaviour::entity::predicate::STRING_PREDICATES;
+use crate::behaviour::entity::regex::StringRegex;
+use crate::behaviour::entity::regex::STRING_REGEXES;
+use crate::behaviour::entity::repeat::StringRepeat;
+use crate::behaviour::entity::repeat::STRING_REPEATS;
+use crate::behaviour::entity::replace::StringReplace;
+use crate::behaviour::entity::replace::STRING_REPLACES;
+use crate::behaviour::entity::similar::StringSimilar;
+use crate::behaviour::entity::similar::STRING_SIMILARS;
+use crate::behaviour::entity::similarity::StringSimilarity;
+use crate::behaviour::entity::similarity::STRING_SIMILARITIES;
+use crate::behaviour::entity::slice::StringSlice;
+use crate::behaviour::entity::slice::STRING_SLICES;
+use crate::behaviour::entity::stringify::StringStringify;
+use crate::behaviour::entity::stringify::STRING_STRINGIFIES;
+use crate::behaviour::entity::template::StringTemplate;
+use crate::behaviour::entity::template::STRING_TEMPLATES;
+use crate::behaviour::entity::validator::StringValidator;
+use crate::behaviour::entity::validator::STRING_VALIDATORS;
+use crate::behaviour::entity::value_operation::StringValueOperation;
+use crate::behaviour::entity::value_operation::STRING_VALUE_OPERATIONS;
+use crate::behaviour::entity::variadic_gate::StringVariadicGate;
+use crate::behaviour::entity::variadic_gate::STRING_VARIADIC_GATES;
 use crate::di::*;
 use crate::model::ReactiveEntityInstance;
 use crate::plugins::EntityBehaviourProvider;
@@ -23,6 +71,78 @@ pub struct StringGateStorage(std::sync::RwLock<std::collections::HashMap<Uuid, s
 #[wrapper]
 pub struct StringComparisonStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringComparison<'static>>>>);
 
+#[wrapper]
+pub struct StringNumberOperationStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringNumberOperation<'static>>>>);
+
+#[wrapper]
+pub struct StringArrayOperationStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringArrayOperation<'static>>>>);
+
+#[wrapper]
+pub struct StringArrayGateStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringArrayGate<'static>>>>);
+
+#[wrapper]
+pub struct StringReplaceStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringReplace<'static>>>>);
+
+#[wrapper]
+pub struct StringSliceStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringSlice<'static>>>>);
+
+#[wrapper]
+pub struct StringInsertStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringInsert<'static>>>>);
+
+#[wrapper]
+pub struct StringNumberGateStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringNumberGate<'static>>>>);
+
+#[wrapper]
+pub struct StringRegexStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringRegex<'static>>>>);
+
+#[wrapper]
+pub struct StringTemplateStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringTemplate<'static>>>>);
+
+#[wrapper]
+pub struct StringJoinStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringJoin<'static>>>>);
+
+#[wrapper]
+pub struct StringVariadicGateStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringVariadicGate<'static>>>>);
+
+#[wrapper]
+pub struct StringPadStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringPad<'static>>>>);
+
+#[wrapper]
+pub struct StringRepeatStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringRepeat<'static>>>>);
+
+#[wrapper]
+pub struct StringFallibleOperationStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringFallibleOperation<'static>>>>);
+
+#[wrapper]
+pub struct StringHashStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringHash<'static>>>>);
+
+#[wrapper]
+pub struct StringParseStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringParse<'static>>>>);
+
+#[wrapper]
+pub struct StringNumberFormatStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringNumberFormat<'static>>>>);
+
+#[wrapper]
+pub struct StringValueOperationStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringValueOperation<'static>>>>);
+
+#[wrapper]
+pub struct StringStringifyStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringStringify<'static>>>>);
+
+#[wrapper]
+pub struct StringSimilarityStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringSimilarity<'static>>>>);
+
+#[wrapper]
+pub struct StringSimilarStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringSimilar<'static>>>>);
+
+#[wrapper]
+pub struct StringArrayComparisonStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringArrayComparison<'static>>>>);
+
+#[wrapper]
+pub struct StringPredicateStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringPredicate<'static>>>>);
+
+#[wrapper]
+pub struct StringValidatorStorage(std::sync::RwLock<std::collections::HashMap<Uuid, std::sync::Arc<StringValidator<'static>>>>);
+
 #[provides]
 fn create_string_operation_storage() -> StringOperationStorage {
     StringOperationStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
@@ -38,6 +158,126 @@ fn create_string_comparison_storage() -> StringComparisonStorage {
     StringComparisonStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
 }
 
+#[provides]
+fn create_string_number_operation_storage() -> StringNumberOperationStorage {
+    StringNumberOperationStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_array_operation_storage() -> StringArrayOperationStorage {
+    StringArrayOperationStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_array_gate_storage() -> StringArrayGateStorage {
+    StringArrayGateStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_replace_storage() -> StringReplaceStorage {
+    StringReplaceStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_slice_storage() -> StringSliceStorage {
+    StringSliceStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_insert_storage() -> StringInsertStorage {
+    StringInsertStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_number_gate_storage() -> StringNumberGateStorage {
+    StringNumberGateStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_regex_storage() -> StringRegexStorage {
+    StringRegexStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_template_storage() -> StringTemplateStorage {
+    StringTemplateStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_join_storage() -> StringJoinStorage {
+    StringJoinStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_variadic_gate_storage() -> StringVariadicGateStorage {
+    StringVariadicGateStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_pad_storage() -> StringPadStorage {
+    StringPadStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_repeat_storage() -> StringRepeatStorage {
+    StringRepeatStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_fallible_operation_storage() -> StringFallibleOperationStorage {
+    StringFallibleOperationStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_hash_storage() -> StringHashStorage {
+    StringHashStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_parse_storage() -> StringParseStorage {
+    StringParseStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_number_format_storage() -> StringNumberFormatStorage {
+    StringNumberFormatStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_value_operation_storage() -> StringValueOperationStorage {
+    StringValueOperationStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_stringify_storage() -> StringStringifyStorage {
+    StringStringifyStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_similarity_storage() -> StringSimilarityStorage {
+    StringSimilarityStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_similar_storage() -> StringSimilarStorage {
+    StringSimilarStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_array_comparison_storage() -> StringArrayComparisonStorage {
+    StringArrayComparisonStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_predicate_storage() -> StringPredicateStorage {
+    StringPredicateStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
+#[provides]
+fn create_string_validator_storage() -> StringValidatorStorage {
+    StringValidatorStorage(std::sync::RwLock::new(std::collections::HashMap::new()))
+}
+
 #[async_trait]
 pub trait StringEntityBehaviourProvider: EntityBehaviourProvider + Send + Sync {
     fn create_string_operation(&self, entity_instance: Arc<ReactiveEntityInstance>);
@@ -46,12 +286,108 @@ pub trait StringEntityBehaviourProvider: EntityBehaviourProvider + Send + Sync {
 
     fn create_string_comparison(&self, entity_instance: Arc<ReactiveEntityInstance>);
 
+    fn create_string_number_operation(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_array_operation(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_array_gate(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_replace(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_slice(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_insert(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_number_gate(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_regex(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_template(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_join(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_variadic_gate(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_pad(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_repeat(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_fallible_operation(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_hash(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_parse(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_number_format(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_value_operation(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_stringify(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_similarity(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_similar(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_array_comparison(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_predicate(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn create_string_validator(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
     fn remove_string_operation(&self, entity_instance: Arc<ReactiveEntityInstance>);
 
     fn remove_string_gate(&self, entity_instance: Arc<ReactiveEntityInstance>);
 
     fn remove_string_comparison(&self, entity_instance: Arc<ReactiveEntityInstance>);
 
+    fn remove_string_number_operation(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_array_operation(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_array_gate(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_replace(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_slice(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_insert(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_number_gate(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_regex(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_template(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_join(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_variadic_gate(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_pad(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_repeat(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_fallible_operation(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_hash(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_parse(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_number_format(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_value_operation(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_stringify(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_similarity(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_similar(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_array_comparison(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_predicate(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
+    fn remove_string_validator(&self, entity_instance: Arc<ReactiveEntityInstance>);
+
     fn remove_by_id(&self, id: Uuid);
 }
 
@@ -59,6 +395,30 @@ pub struct StringEntityBehaviourProviderImpl {
     string_operations: StringOperationStorage,
     string_gates: StringGateStorage,
     string_comparisons: StringComparisonStorage,
+    string_number_operations: StringNumberOperationStorage,
+    string_array_operations: StringArrayOperationStorage,
+    string_array_gates: StringArrayGateStorage,
+    string_replaces: StringReplaceStorage,
+    string_slices: StringSliceStorage,
+    string_inserts: StringInsertStorage,
+    string_number_gates: StringNumberGateStorage,
+    string_regexs: StringRegexStorage,
+    string_templates: StringTemplateStorage,
+    string_joins: StringJoinStorage,
+    string_variadic_gates: StringVariadicGateStorage,
+    string_pads: StringPadStorage,
+    string_repeats: StringRepeatStorage,
+    string_fallible_operations: StringFallibleOperationStorage,
+    string_hashs: StringHashStorage,
+    string_parses: StringParseStorage,
+    string_number_formats: StringNumberFormatStorage,
+    string_value_operations: StringValueOperationStorage,
+    string_stringifys: StringStringifyStorage,
+    string_similaritys: StringSimilarityStorage,
+    string_similars: StringSimilarStorage,
+    string_array_comparisons: StringArrayComparisonStorage,
+    string_predicates: StringPredicateStorage,
+    string_validators: StringValidatorStorage,
 }
 
 interfaces!(StringEntityBehaviourProviderImpl: dyn EntityBehaviourProvider);
@@ -71,6 +431,30 @@ impl StringEntityBehaviourProviderImpl {
             string_operations: create_string_operation_storage(),
             string_gates: create_string_gate_storage(),
             string_comparisons: create_string_comparison_storage(),
+            string_number_operations: create_string_number_operation_storage(),
+            string_array_operations: create_string_array_operation_storage(),
+            string_array_gates: create_string_array_gate_storage(),
+            string_replaces: create_string_replace_storage(),
+            string_slices: create_string_slice_storage(),
+            string_inserts: create_string_insert_storage(),
+            string_number_gates: create_string_number_gate_storage(),
+            string_regexs: create_string_regex_storage(),
+            string_templates: create_string_template_storage(),
+            string_joins: create_string_join_storage(),
+            string_variadic_gates: create_string_variadic_gate_storage(),
+            string_pads: create_string_pad_storage(),
+            string_repeats: create_string_repeat_storage(),
+            string_fallible_operations: create_string_fallible_operation_storage(),
+            string_hashs: create_string_hash_storage(),
+            string_parses: create_string_parse_storage(),
+            string_number_formats: create_string_number_format_storage(),
+            string_value_operations: create_string_value_operation_storage(),
+            string_stringifys: create_string_stringify_storage(),
+            string_similaritys: create_string_similarity_storage(),
+            string_similars: create_string_similar_storage(),
+            string_array_comparisons: create_string_array_comparison_storage(),
+            string_predicates: create_string_predicate_storage(),
+            string_validators: create_string_validator_storage(),
         }
     }
 }
@@ -120,6 +504,346 @@ impl StringEntityBehaviourProvider for StringEntityBehaviourProviderImpl {
         }
     }
 
+    fn create_string_number_operation(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_NUMBER_OPERATIONS.get(entity_instance.type_name.as_str());
+        let string_number_operation = match function {
+            Some(function) => Some(Arc::new(StringNumberOperation::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_number_operation.is_some() {
+            self.string_number_operations.0.write().unwrap().insert(id, string_number_operation.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_number_operation to entity instance {}", id);
+        }
+    }
+
+    fn create_string_array_operation(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_ARRAY_OPERATIONS.get(entity_instance.type_name.as_str());
+        let string_array_operation = match function {
+            Some(function) => Some(Arc::new(StringArrayOperation::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_array_operation.is_some() {
+            self.string_array_operations.0.write().unwrap().insert(id, string_array_operation.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_array_operation to entity instance {}", id);
+        }
+    }
+
+    fn create_string_array_gate(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_ARRAY_GATES.get(entity_instance.type_name.as_str());
+        let string_array_gate = match function {
+            Some(function) => Some(Arc::new(StringArrayGate::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_array_gate.is_some() {
+            self.string_array_gates.0.write().unwrap().insert(id, string_array_gate.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_array_gate to entity instance {}", id);
+        }
+    }
+
+    fn create_string_replace(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_REPLACES.get(entity_instance.type_name.as_str());
+        let string_replace = match function {
+            Some(function) => Some(Arc::new(StringReplace::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_replace.is_some() {
+            self.string_replaces.0.write().unwrap().insert(id, string_replace.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_replace to entity instance {}", id);
+        }
+    }
+
+    fn create_string_slice(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_SLICES.get(entity_instance.type_name.as_str());
+        let string_slice = match function {
+            Some(function) => Some(Arc::new(StringSlice::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_slice.is_some() {
+            self.string_slices.0.write().unwrap().insert(id, string_slice.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_slice to entity instance {}", id);
+        }
+    }
+
+    fn create_string_insert(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_INSERTS.get(entity_instance.type_name.as_str());
+        let string_insert = match function {
+            Some(function) => Some(Arc::new(StringInsert::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_insert.is_some() {
+            self.string_inserts.0.write().unwrap().insert(id, string_insert.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_insert to entity instance {}", id);
+        }
+    }
+
+    fn create_string_number_gate(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_NUMBER_GATES.get(entity_instance.type_name.as_str());
+        let string_number_gate = match function {
+            Some(function) => Some(Arc::new(StringNumberGate::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_number_gate.is_some() {
+            self.string_number_gates.0.write().unwrap().insert(id, string_number_gate.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_number_gate to entity instance {}", id);
+        }
+    }
+
+    fn create_string_regex(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_REGEXES.get(entity_instance.type_name.as_str());
+        let string_regex = match function {
+            Some(function) => Some(Arc::new(StringRegex::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_regex.is_some() {
+            self.string_regexs.0.write().unwrap().insert(id, string_regex.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_regex to entity instance {}", id);
+        }
+    }
+
+    fn create_string_template(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_TEMPLATES.get(entity_instance.type_name.as_str());
+        let string_template = match function {
+            Some(function) => Some(Arc::new(StringTemplate::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_template.is_some() {
+            self.string_templates.0.write().unwrap().insert(id, string_template.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_template to entity instance {}", id);
+        }
+    }
+
+    fn create_string_join(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_JOINS.get(entity_instance.type_name.as_str());
+        let string_join = match function {
+            Some(function) => Some(Arc::new(StringJoin::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_join.is_some() {
+            self.string_joins.0.write().unwrap().insert(id, string_join.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_join to entity instance {}", id);
+        }
+    }
+
+    fn create_string_variadic_gate(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_VARIADIC_GATES.get(entity_instance.type_name.as_str());
+        let string_variadic_gate = match function {
+            Some(function) => Some(Arc::new(StringVariadicGate::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_variadic_gate.is_some() {
+            self.string_variadic_gates.0.write().unwrap().insert(id, string_variadic_gate.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_variadic_gate to entity instance {}", id);
+        }
+    }
+
+    fn create_string_pad(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_PADS.get(entity_instance.type_name.as_str());
+        let string_pad = match function {
+            Some(function) => Some(Arc::new(StringPad::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_pad.is_some() {
+            self.string_pads.0.write().unwrap().insert(id, string_pad.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_pad to entity instance {}", id);
+        }
+    }
+
+    fn create_string_repeat(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_REPEATS.get(entity_instance.type_name.as_str());
+        let string_repeat = match function {
+            Some(function) => Some(Arc::new(StringRepeat::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_repeat.is_some() {
+            self.string_repeats.0.write().unwrap().insert(id, string_repeat.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_repeat to entity instance {}", id);
+        }
+    }
+
+    fn create_string_fallible_operation(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_FALLIBLE_OPERATIONS.get(entity_instance.type_name.as_str());
+        let string_fallible_operation = match function {
+            Some(function) => Some(Arc::new(StringFallibleOperation::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_fallible_operation.is_some() {
+            self.string_fallible_operations
+                .0
+                .write()
+                .unwrap()
+                .insert(id, string_fallible_operation.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_fallible_operation to entity instance {}", id);
+        }
+    }
+
+    fn create_string_hash(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_HASHES.get(entity_instance.type_name.as_str());
+        let string_hash = match function {
+            Some(function) => Some(Arc::new(StringHash::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_hash.is_some() {
+            self.string_hashs.0.write().unwrap().insert(id, string_hash.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_hash to entity instance {}", id);
+        }
+    }
+
+    fn create_string_parse(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_PARSES.get(entity_instance.type_name.as_str());
+        let string_parse = match function {
+            Some(function) => Some(Arc::new(StringParse::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_parse.is_some() {
+            self.string_parses.0.write().unwrap().insert(id, string_parse.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_parse to entity instance {}", id);
+        }
+    }
+
+    fn create_string_number_format(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_NUMBER_FORMATS.get(entity_instance.type_name.as_str());
+        let string_number_format = match function {
+            Some(function) => Some(Arc::new(StringNumberFormat::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_number_format.is_some() {
+            self.string_number_formats.0.write().unwrap().insert(id, string_number_format.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_number_format to entity instance {}", id);
+        }
+    }
+
+    fn create_string_value_operation(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_VALUE_OPERATIONS.get(entity_instance.type_name.as_str());
+        let string_value_operation = match function {
+            Some(function) => Some(Arc::new(StringValueOperation::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_value_operation.is_some() {
+            self.string_value_operations.0.write().unwrap().insert(id, string_value_operation.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_value_operation to entity instance {}", id);
+        }
+    }
+
+    fn create_string_stringify(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_STRINGIFIES.get(entity_instance.type_name.as_str());
+        let string_stringify = match function {
+            Some(function) => Some(Arc::new(StringStringify::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_stringify.is_some() {
+            self.string_stringifys.0.write().unwrap().insert(id, string_stringify.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_stringify to entity instance {}", id);
+        }
+    }
+
+    fn create_string_similarity(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_SIMILARITIES.get(entity_instance.type_name.as_str());
+        let string_similarity = match function {
+            Some(function) => Some(Arc::new(StringSimilarity::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_similarity.is_some() {
+            self.string_similaritys.0.write().unwrap().insert(id, string_similarity.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_similarity to entity instance {}", id);
+        }
+    }
+
+    fn create_string_similar(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_SIMILARS.get(entity_instance.type_name.as_str());
+        let string_similar = match function {
+            Some(function) => Some(Arc::new(StringSimilar::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_similar.is_some() {
+            self.string_similars.0.write().unwrap().insert(id, string_similar.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_similar to entity instance {}", id);
+        }
+    }
+
+    fn create_string_array_comparison(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_ARRAY_COMPARISONS.get(entity_instance.type_name.as_str());
+        let string_array_comparison = match function {
+            Some(function) => Some(Arc::new(StringArrayComparison::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_array_comparison.is_some() {
+            self.string_array_comparisons.0.write().unwrap().insert(id, string_array_comparison.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_array_comparison to entity instance {}", id);
+        }
+    }
+
+    fn create_string_predicate(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_PREDICATES.get(entity_instance.type_name.as_str());
+        let string_predicate = match function {
+            Some(function) => Some(Arc::new(StringPredicate::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_predicate.is_some() {
+            self.string_predicates.0.write().unwrap().insert(id, string_predicate.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_predicate to entity instance {}", id);
+        }
+    }
+
+    fn create_string_validator(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        let id = entity_instance.id;
+        let function = STRING_VALIDATORS.get(entity_instance.type_name.as_str());
+        let string_validator = match function {
+            Some(function) => Some(Arc::new(StringValidator::new(entity_instance.clone(), *function))),
+            None => None,
+        };
+        if string_validator.is_some() {
+            self.string_validators.0.write().unwrap().insert(id, string_validator.unwrap());
+            entity_instance.add_behaviour(entity_instance.type_name.as_str());
+            debug!("Added behaviour string_validator to entity instance {}", id);
+        }
+    }
+
     fn remove_string_operation(&self, entity_instance: Arc<ReactiveEntityInstance>) {
         if let Some(_) = self.string_operations.0.write().unwrap().remove(&entity_instance.id) {
             entity_instance.remove_behaviour(entity_instance.type_name.as_str());
@@ -141,6 +865,174 @@ impl StringEntityBehaviourProvider for StringEntityBehaviourProviderImpl {
         }
     }
 
+    fn remove_string_number_operation(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_number_operations.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_number_operation from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_array_operation(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_array_operations.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_array_operation from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_array_gate(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_array_gates.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_array_gate from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_replace(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_replaces.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_replace from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_slice(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_slices.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_slice from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_insert(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_inserts.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_insert from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_number_gate(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_number_gates.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_number_gate from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_regex(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_regexs.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_regex from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_template(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_templates.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_template from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_join(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_joins.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_join from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_variadic_gate(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_variadic_gates.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_variadic_gate from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_pad(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_pads.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_pad from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_repeat(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_repeats.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_repeat from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_fallible_operation(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_fallible_operations.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_fallible_operation from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_hash(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_hashs.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_hash from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_parse(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_parses.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_parse from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_number_format(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_number_formats.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_number_format from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_value_operation(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_value_operations.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_value_operation from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_stringify(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_stringifys.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_stringify from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_similarity(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_similaritys.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_similarity from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_similar(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_similars.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_similar from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_array_comparison(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_array_comparisons.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_array_comparison from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_predicate(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_predicates.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_predicate from entity instance {}", entity_instance.id);
+        }
+    }
+
+    fn remove_string_validator(&self, entity_instance: Arc<ReactiveEntityInstance>) {
+        if let Some(_) = self.string_validators.0.write().unwrap().remove(&entity_instance.id) {
+            entity_instance.remove_behaviour(entity_instance.type_name.as_str());
+            debug!("Removed behaviour string_validator from entity instance {}", entity_instance.id);
+        }
+    }
+
     fn remove_by_id(&self, id: Uuid) {
         if self.string_operations.0.write().unwrap().contains_key(&id) {
             if let Some(_) = self.string_operations.0.write().unwrap().remove(&id) {
@@ -152,6 +1044,126 @@ impl StringEntityBehaviourProvider for StringEntityBehaviourProviderImpl {
                 debug!("Removed behaviour string_gate from entity instance {}", id);
             }
         }
+        if self.string_number_operations.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_number_operations.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_number_operation from entity instance {}", id);
+            }
+        }
+        if self.string_array_operations.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_array_operations.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_array_operation from entity instance {}", id);
+            }
+        }
+        if self.string_array_gates.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_array_gates.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_array_gate from entity instance {}", id);
+            }
+        }
+        if self.string_replaces.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_replaces.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_replace from entity instance {}", id);
+            }
+        }
+        if self.string_slices.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_slices.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_slice from entity instance {}", id);
+            }
+        }
+        if self.string_inserts.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_inserts.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_insert from entity instance {}", id);
+            }
+        }
+        if self.string_number_gates.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_number_gates.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_number_gate from entity instance {}", id);
+            }
+        }
+        if self.string_regexs.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_regexs.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_regex from entity instance {}", id);
+            }
+        }
+        if self.string_templates.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_templates.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_template from entity instance {}", id);
+            }
+        }
+        if self.string_joins.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_joins.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_join from entity instance {}", id);
+            }
+        }
+        if self.string_variadic_gates.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_variadic_gates.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_variadic_gate from entity instance {}", id);
+            }
+        }
+        if self.string_pads.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_pads.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_pad from entity instance {}", id);
+            }
+        }
+        if self.string_repeats.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_repeats.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_repeat from entity instance {}", id);
+            }
+        }
+        if self.string_fallible_operations.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_fallible_operations.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_fallible_operation from entity instance {}", id);
+            }
+        }
+        if self.string_hashs.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_hashs.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_hash from entity instance {}", id);
+            }
+        }
+        if self.string_parses.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_parses.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_parse from entity instance {}", id);
+            }
+        }
+        if self.string_number_formats.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_number_formats.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_number_format from entity instance {}", id);
+            }
+        }
+        if self.string_value_operations.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_value_operations.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_value_operation from entity instance {}", id);
+            }
+        }
+        if self.string_stringifys.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_stringifys.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_stringify from entity instance {}", id);
+            }
+        }
+        if self.string_similaritys.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_similaritys.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_similarity from entity instance {}", id);
+            }
+        }
+        if self.string_similars.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_similars.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_similar from entity instance {}", id);
+            }
+        }
+        if self.string_array_comparisons.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_array_comparisons.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_array_comparison from entity instance {}", id);
+            }
+        }
+        if self.string_predicates.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_predicates.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_predicate from entity instance {}", id);
+            }
+        }
+        if self.string_validators.0.write().unwrap().contains_key(&id) {
+            if let Some(_) = self.string_validators.0.write().unwrap().remove(&id) {
+                debug!("Removed behaviour string_validator from entity instance {}", id);
+            }
+        }
     }
 }
 
@@ -160,12 +1172,60 @@ impl EntityBehaviourProvider for StringEntityBehaviourProviderImpl {
         self.create_string_operation(entity_instance.clone());
         self.create_string_gate(entity_instance.clone());
         self.create_string_comparison(entity_instance.clone());
+        self.create_string_number_operation(entity_instance.clone());
+        self.create_string_array_operation(entity_instance.clone());
+        self.create_string_array_gate(entity_instance.clone());
+        self.create_string_replace(entity_instance.clone());
+        self.create_string_slice(entity_instance.clone());
+        self.create_string_insert(entity_instance.clone());
+        self.create_string_number_gate(entity_instance.clone());
+        self.create_string_regex(entity_instance.clone());
+        self.create_string_template(entity_instance.clone());
+        self.create_string_join(entity_instance.clone());
+        self.create_string_variadic_gate(entity_instance.clone());
+        self.create_string_pad(entity_instance.clone());
+        self.create_string_repeat(entity_instance.clone());
+        self.create_string_fallible_operation(entity_instance.clone());
+        self.create_string_hash(entity_instance.clone());
+        self.create_string_parse(entity_instance.clone());
+        self.create_string_number_format(entity_instance.clone());
+        self.create_string_value_operation(entity_instance.clone());
+        self.create_string_stringify(entity_instance.clone());
+        self.create_string_similarity(entity_instance.clone());
+        self.create_string_similar(entity_instance.clone());
+        self.create_string_array_comparison(entity_instance.clone());
+        self.create_string_predicate(entity_instance.clone());
+        self.create_string_validator(entity_instance.clone());
     }
 
     fn remove_behaviours(&self, entity_instance: Arc<ReactiveEntityInstance>) {
         self.remove_string_operation(entity_instance.clone());
         self.remove_string_gate(entity_instance.clone());
         self.remove_string_comparison(entity_instance.clone());
+        self.remove_string_number_operation(entity_instance.clone());
+        self.remove_string_array_operation(entity_instance.clone());
+        self.remove_string_array_gate(entity_instance.clone());
+        self.remove_string_replace(entity_instance.clone());
+        self.remove_string_slice(entity_instance.clone());
+        self.remove_string_insert(entity_instance.clone());
+        self.remove_string_number_gate(entity_instance.clone());
+        self.remove_string_regex(entity_instance.clone());
+        self.remove_string_template(entity_instance.clone());
+        self.remove_string_join(entity_instance.clone());
+        self.remove_string_variadic_gate(entity_instance.clone());
+        self.remove_string_pad(entity_instance.clone());
+        self.remove_string_repeat(entity_instance.clone());
+        self.remove_string_fallible_operation(entity_instance.clone());
+        self.remove_string_hash(entity_instance.clone());
+        self.remove_string_parse(entity_instance.clone());
+        self.remove_string_number_format(entity_instance.clone());
+        self.remove_string_value_operation(entity_instance.clone());
+        self.remove_string_stringify(entity_instance.clone());
+        self.remove_string_similarity(entity_instance.clone());
+        self.remove_string_similar(entity_instance.clone());
+        self.remove_string_array_comparison(entity_instance.clone());
+        self.remove_string_predicate(entity_instance.clone());
+        self.remove_string_validator(entity_instance.clone());
     }
 
     fn remove_behaviours_by_id(&self, id: Uuid) {
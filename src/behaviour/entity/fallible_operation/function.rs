@@ -0,0 +1,29 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use percent_encoding::percent_decode_str;
+
+/// String operations which may fail. Failures are reported as error message.
+pub type StringFallibleOperationFunction = fn(String) -> Result<String, String>;
+
+pub const FN_BASE64_DECODE: StringFallibleOperationFunction = |lhs| to_utf8(base64::decode_config(lhs.trim_end_matches('='), base64::STANDARD_NO_PAD));
+pub const FN_BASE64URL_DECODE: StringFallibleOperationFunction = |lhs| to_utf8(base64::decode_config(lhs.trim_end_matches('='), base64::URL_SAFE_NO_PAD));
+pub const FN_HEX_DECODE: StringFallibleOperationFunction = |lhs| to_utf8(hex::decode(lhs));
+pub const FN_PERCENT_DECODE: StringFallibleOperationFunction =
+    |lhs| percent_decode_str(lhs.as_str()).decode_utf8().map(String::from).map_err(|e| e.to_string());
+
+lazy_static! {
+    pub static ref STRING_FALLIBLE_OPERATIONS: HashMap<&'static str, StringFallibleOperationFunction> = vec![
+        ("base64_decode", FN_BASE64_DECODE),
+        ("base64url_decode", FN_BASE64URL_DECODE),
+        ("hex_decode", FN_HEX_DECODE),
+        ("percent_decode", FN_PERCENT_DECODE),
+    ]
+    .into_iter()
+    .collect();
+}
+
+/// The decoded bytes have to be valid UTF-8 because they are stored in a string property.
+fn to_utf8<E: ToString>(bytes: Result<Vec<u8>, E>) -> Result<String, String> {
+    String::from_utf8(bytes.map_err(|e| e.to_string())?).map_err(|e| e.to_string())
+}
@@ -0,0 +1,8 @@
+pub use function::StringFallibleOperationFunction;
+pub use function::STRING_FALLIBLE_OPERATIONS;
+pub use string_fallible_operation::StringFallibleOperation;
+pub use string_fallible_operation_properties::StringFallibleOperationProperties;
+
+pub mod function;
+pub mod string_fallible_operation;
+pub mod string_fallible_operation_properties;
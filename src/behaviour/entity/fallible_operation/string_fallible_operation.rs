@@ -0,0 +1,100 @@
+use std::convert::AsRef;
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::fallible_operation::string_fallible_operation_properties::StringFallibleOperationProperties;
+use crate::behaviour::entity::fallible_operation::StringFallibleOperationFunction;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+/// Generic implementation of fallible string operations with one input and one result. Failures are reported on the
+/// error output and leave the result untouched.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringFallibleOperation<'a> {
+    pub f: StringFallibleOperationFunction,
+
+    pub internal_result: RwLock<Stream<'a, Result<String, String>>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringFallibleOperation<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringFallibleOperationFunction) -> StringFallibleOperation<'static> {
+        let handle_id = e.properties.get(StringFallibleOperationProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let internal_result = e
+            .properties
+            .get(StringFallibleOperationProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(move |v| match v.as_str() {
+                Some(lhs) => f(String::from(lhs)),
+                None => Err(String::from("The lhs is not a string")),
+            });
+        let string_fallible_operation = StringFallibleOperation {
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_fallible_operation.internal_result.read().unwrap().observe_with_handle(
+            move |v| match v {
+                Ok(result) => {
+                    debug!("Setting result of string fallible operation: {}", result);
+                    e.set(StringFallibleOperationProperties::ERROR.to_string(), json!(""));
+                    e.set(StringFallibleOperationProperties::RESULT.to_string(), json!(result));
+                }
+                Err(error) => {
+                    debug!("Setting error of string fallible operation: {}", error);
+                    e.set(StringFallibleOperationProperties::ERROR.to_string(), json!(error));
+                }
+            },
+            handle_id,
+        );
+
+        string_fallible_operation
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringFallibleOperation<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string fallible operation {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringFallibleOperation<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringFallibleOperationProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringFallibleOperationProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringFallibleOperation<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string fallible operation");
+        self.disconnect();
+    }
+}
@@ -0,0 +1,48 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringFallibleOperationProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "result")]
+    RESULT,
+    #[strum(serialize = "error")]
+    ERROR,
+}
+
+impl StringFallibleOperationProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringFallibleOperationProperties::LHS => json!(""),
+            StringFallibleOperationProperties::RESULT => json!(""),
+            StringFallibleOperationProperties::ERROR => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringFallibleOperationProperties::LHS),
+            NamedProperty::from(StringFallibleOperationProperties::RESULT),
+            NamedProperty::from(StringFallibleOperationProperties::ERROR),
+        ]
+    }
+}
+
+impl From<StringFallibleOperationProperties> for NamedProperty {
+    fn from(p: StringFallibleOperationProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringFallibleOperationProperties> for String {
+    fn from(p: StringFallibleOperationProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -0,0 +1,47 @@
+use std::collections::HashMap;
+use std::hash::Hasher;
+
+use lazy_static::lazy_static;
+use md5::Md5;
+use sha1::Sha1;
+use sha2::{Digest, Sha256};
+use twox_hash::XxHash64;
+
+/// Calculates the digest of the UTF-8 bytes of a string.
+pub type StringHashFunction = fn(&[u8]) -> Vec<u8>;
+
+pub const FN_SHA256: StringHashFunction = |bytes| Sha256::digest(bytes).to_vec();
+pub const FN_SHA1: StringHashFunction = |bytes| Sha1::digest(bytes).to_vec();
+pub const FN_MD5: StringHashFunction = |bytes| Md5::digest(bytes).to_vec();
+/// The checksum is returned in big-endian byte order.
+pub const FN_CRC32: StringHashFunction = |bytes| crc32fast::hash(bytes).to_be_bytes().to_vec();
+/// XXH64 with seed 0. The hash is returned in big-endian byte order.
+pub const FN_XXHASH: StringHashFunction = |bytes| {
+    let mut hasher = XxHash64::with_seed(0);
+    hasher.write(bytes);
+    hasher.finish().to_be_bytes().to_vec()
+};
+
+lazy_static! {
+    pub static ref STRING_HASHES: HashMap<&'static str, StringHashFunction> = vec![
+        ("sha256", FN_SHA256),
+        ("sha1", FN_SHA1),
+        ("md5", FN_MD5),
+        ("crc32", FN_CRC32),
+        ("xxhash", FN_XXHASH),
+    ]
+    .into_iter()
+    .collect();
+}
+
+pub const ENCODING_HEX: &str = "hex";
+pub const ENCODING_BASE64: &str = "base64";
+
+/// Encodes the digest as lowercase hex or as standard base64. Other encodings are rejected.
+pub fn encode_digest(digest: Vec<u8>, encoding: &str) -> Result<String, String> {
+    match encoding {
+        ENCODING_HEX => Ok(hex::encode(digest)),
+        ENCODING_BASE64 => Ok(base64::encode(digest)),
+        _ => Err(format!("Unknown encoding {} (expected {} or {})", encoding, ENCODING_HEX, ENCODING_BASE64)),
+    }
+}
@@ -0,0 +1,8 @@
+pub use function::StringHashFunction;
+pub use function::STRING_HASHES;
+pub use string_hash::StringHash;
+pub use string_hash_properties::StringHashProperties;
+
+pub mod function;
+pub mod string_hash;
+pub mod string_hash_properties;
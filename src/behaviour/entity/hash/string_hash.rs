@@ -0,0 +1,134 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::hash::function::encode_digest;
+use crate::behaviour::entity::hash::string_hash_properties::StringHashProperties;
+use crate::behaviour::entity::hash::StringHashFunction;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::expression::{Expression, ExpressionValue, OperatorPosition};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+pub type StringHashExpressionValue = ExpressionValue<Value>;
+
+/// Generic implementation of hash digests with two inputs (LHS, ENCODING) and one result. Unknown encodings are
+/// reported on the error output.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringHash<'a> {
+    pub lhs: RwLock<Stream<'a, StringHashExpressionValue>>,
+
+    pub encoding: RwLock<Stream<'a, StringHashExpressionValue>>,
+
+    pub f: StringHashFunction,
+
+    pub internal_result: RwLock<Stream<'a, Result<String, String>>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringHash<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringHashFunction) -> StringHash<'static> {
+        let lhs = e
+            .properties
+            .get(StringHashProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringHashExpressionValue { (OperatorPosition::LHS, v.clone()) });
+        let encoding = e
+            .properties
+            .get(StringHashProperties::ENCODING.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringHashExpressionValue { (OperatorPosition::RHS, v.clone()) });
+
+        let expression = lhs.merge(&encoding).fold(
+            Expression::new(
+                e.get(StringHashProperties::LHS.as_ref())
+                    .unwrap_or_else(|| StringHashProperties::LHS.default_value()),
+                e.get(StringHashProperties::ENCODING.as_ref())
+                    .unwrap_or_else(|| StringHashProperties::ENCODING.default_value()),
+            ),
+            |old_state, (o, value)| match *o {
+                OperatorPosition::LHS => old_state.lhs(value.clone()),
+                OperatorPosition::RHS => old_state.rhs(value.clone()),
+            },
+        );
+
+        // The internal result
+        let internal_result = expression.map(move |e| match e.rhs.as_str() {
+            Some(encoding) => encode_digest(f(e.lhs.as_str().unwrap_or_default().as_bytes()), encoding),
+            None => Err(String::from("The encoding is not a string")),
+        });
+
+        let handle_id = e.properties.get(StringHashProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_hash = StringHash {
+            lhs: RwLock::new(lhs),
+            encoding: RwLock::new(encoding),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_hash.internal_result.read().unwrap().observe_with_handle(
+            move |v| match v {
+                Ok(result) => {
+                    debug!("Setting result of string hash: {}", result);
+                    e.set(StringHashProperties::ERROR.to_string(), json!(""));
+                    e.set(StringHashProperties::RESULT.to_string(), json!(result));
+                }
+                Err(error) => {
+                    debug!("Setting error of string hash: {}", error);
+                    e.set(StringHashProperties::ERROR.to_string(), json!(error));
+                }
+            },
+            handle_id,
+        );
+
+        string_hash
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringHash<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string hash {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringHash<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringHashProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringHashProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringHash<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string hash");
+        self.disconnect();
+    }
+}
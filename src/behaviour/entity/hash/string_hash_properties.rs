@@ -0,0 +1,52 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringHashProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "encoding")]
+    ENCODING,
+    #[strum(serialize = "result")]
+    RESULT,
+    #[strum(serialize = "error")]
+    ERROR,
+}
+
+impl StringHashProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringHashProperties::LHS => json!(""),
+            StringHashProperties::ENCODING => json!("hex"),
+            StringHashProperties::RESULT => json!(""),
+            StringHashProperties::ERROR => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringHashProperties::LHS),
+            NamedProperty::from(StringHashProperties::ENCODING),
+            NamedProperty::from(StringHashProperties::RESULT),
+            NamedProperty::from(StringHashProperties::ERROR),
+        ]
+    }
+}
+
+impl From<StringHashProperties> for NamedProperty {
+    fn from(p: StringHashProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringHashProperties> for String {
+    fn from(p: StringHashProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -0,0 +1,22 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+use crate::behaviour::entity::slice::function::{byte_offset, resolve_char_index};
+
+/// Inserts rhs into lhs at the given position.
+///
+/// The position is counted in unicode scalar values (chars), not in bytes, so the insertion never splits a multi-byte
+/// character. Negative positions count from the end of the string. Positions out of range are clamped to the bounds of
+/// the string.
+pub type StringInsertFunction = fn(String, String, i64) -> String;
+
+pub const FN_INSERT: StringInsertFunction = |lhs, rhs, position| {
+    let position = resolve_char_index(lhs.chars().count(), position);
+    let mut result = lhs;
+    result.insert_str(byte_offset(result.as_str(), position), rhs.as_str());
+    result
+};
+
+lazy_static! {
+    pub static ref STRING_INSERTS: HashMap<&'static str, StringInsertFunction> = vec![("insert", FN_INSERT)].into_iter().collect();
+}
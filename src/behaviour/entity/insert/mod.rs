@@ -0,0 +1,8 @@
+pub use function::StringInsertFunction;
+pub use function::STRING_INSERTS;
+pub use string_insert::StringInsert;
+pub use string_insert_properties::StringInsertProperties;
+
+pub mod function;
+pub mod string_insert;
+pub mod string_insert_properties;
@@ -0,0 +1,182 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::insert::string_insert_properties::StringInsertProperties;
+use crate::behaviour::entity::insert::StringInsertFunction;
+use crate::behaviour::entity::value::to_index;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::gate::Gate;
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+#[derive(Debug, Copy, Clone)]
+pub enum StringInsertPosition {
+    LHS,
+    RHS,
+    POSITION,
+}
+
+pub type StringInsertExpressionValue = (StringInsertPosition, Value);
+
+/// The state of the string inputs and the numeric position of a string insert.
+#[derive(Debug, Clone)]
+pub struct StringInsertExpression {
+    pub lhs: String,
+    pub rhs: String,
+    pub position: i64,
+}
+
+impl StringInsertExpression {
+    /// Initializes the expression with the current values of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance) -> Self {
+        StringInsertExpression {
+            lhs: e
+                .get(StringInsertProperties::LHS.as_ref())
+                .and_then(|v| v.as_str().map(String::from))
+                .unwrap_or_default(),
+            rhs: e
+                .get(StringInsertProperties::RHS.as_ref())
+                .and_then(|v| v.as_str().map(String::from))
+                .unwrap_or_default(),
+            position: e.get(StringInsertProperties::POSITION.as_ref()).and_then(|v| to_index(&v)).unwrap_or_default(),
+        }
+    }
+
+    pub fn set(self, position: StringInsertPosition, value: &Value) -> Self {
+        match position {
+            StringInsertPosition::LHS => StringInsertExpression {
+                lhs: value.as_str().map(String::from).unwrap_or_default(),
+                ..self
+            },
+            StringInsertPosition::RHS => StringInsertExpression {
+                rhs: value.as_str().map(String::from).unwrap_or_default(),
+                ..self
+            },
+            StringInsertPosition::POSITION => StringInsertExpression {
+                position: to_index(value).unwrap_or_default(),
+                ..self
+            },
+        }
+    }
+}
+
+/// Generic implementation of string operations with two string inputs (LHS, RHS), a numeric input (POSITION) and one
+/// result.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringInsert<'a> {
+    pub lhs: RwLock<Stream<'a, StringInsertExpressionValue>>,
+
+    pub rhs: RwLock<Stream<'a, StringInsertExpressionValue>>,
+
+    pub position: RwLock<Stream<'a, StringInsertExpressionValue>>,
+
+    pub f: StringInsertFunction,
+
+    pub internal_result: RwLock<Stream<'a, String>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringInsert<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringInsertFunction) -> StringInsert<'static> {
+        let lhs = e
+            .properties
+            .get(StringInsertProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringInsertExpressionValue { (StringInsertPosition::LHS, v.clone()) });
+        let rhs = e
+            .properties
+            .get(StringInsertProperties::RHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringInsertExpressionValue { (StringInsertPosition::RHS, v.clone()) });
+        let position = e
+            .properties
+            .get(StringInsertProperties::POSITION.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringInsertExpressionValue { (StringInsertPosition::POSITION, v.clone()) });
+
+        let expression = lhs
+            .merge(&rhs)
+            .merge(&position)
+            .fold(StringInsertExpression::new(&e), |old_state, (o, value)| old_state.set(*o, value));
+
+        // The internal result
+        let internal_result = expression.map(move |e| f(e.lhs.clone(), e.rhs.clone(), e.position));
+
+        let handle_id = e.properties.get(StringInsertProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_insert = StringInsert {
+            lhs: RwLock::new(lhs),
+            rhs: RwLock::new(rhs),
+            position: RwLock::new(position),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_insert.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string insert: {}", v);
+                e.set(StringInsertProperties::RESULT.to_string(), json!(*v));
+            },
+            handle_id,
+        );
+
+        string_insert
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringInsert<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string insert {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringInsert<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringInsertProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringInsertProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+impl Gate for StringInsert<'_> {
+    fn rhs(&self, value: Value) {
+        self.entity.set(StringInsertProperties::RHS.as_ref(), value);
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringInsert<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string insert");
+        self.disconnect();
+    }
+}
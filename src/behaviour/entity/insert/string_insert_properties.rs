@@ -0,0 +1,52 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringInsertProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "rhs")]
+    RHS,
+    #[strum(serialize = "position")]
+    POSITION,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringInsertProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringInsertProperties::LHS => json!(""),
+            StringInsertProperties::RHS => json!(""),
+            StringInsertProperties::POSITION => json!(0),
+            StringInsertProperties::RESULT => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringInsertProperties::LHS),
+            NamedProperty::from(StringInsertProperties::RHS),
+            NamedProperty::from(StringInsertProperties::POSITION),
+            NamedProperty::from(StringInsertProperties::RESULT),
+        ]
+    }
+}
+
+impl From<StringInsertProperties> for NamedProperty {
+    fn from(p: StringInsertProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringInsertProperties> for String {
+    fn from(p: StringInsertProperties) -> Self {
+        p.to_string()
+    }
+}
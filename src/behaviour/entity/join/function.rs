@@ -0,0 +1,27 @@
+use lazy_static::lazy_static;
+use serde_json::Value;
+use std::collections::HashMap;
+
+pub type StringJoinFunction = fn(Vec<Value>, String) -> String;
+
+/// Joins the elements of lhs with the separator. Elements which are not strings are converted using
+/// `value_to_string`.
+pub const FN_JOIN: StringJoinFunction = |lhs, separator| lhs.iter().map(value_to_string).collect::<Vec<String>>().join(separator.as_str());
+
+lazy_static! {
+    pub static ref STRING_JOINS: HashMap<&'static str, StringJoinFunction> = vec![("join", FN_JOIN)].into_iter().collect();
+}
+
+/// Converts any JSON value into a string.
+///
+/// Strings are taken as they are, null becomes the empty string, booleans and numbers are formatted and arrays and
+/// objects are serialized as JSON.
+pub fn value_to_string(value: &Value) -> String {
+    match value {
+        Value::Null => String::new(),
+        Value::String(s) => s.clone(),
+        Value::Bool(b) => b.to_string(),
+        Value::Number(n) => n.to_string(),
+        Value::Array(_) | Value::Object(_) => value.to_string(),
+    }
+}
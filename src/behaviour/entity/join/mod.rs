@@ -0,0 +1,8 @@
+pub use function::StringJoinFunction;
+pub use function::STRING_JOINS;
+pub use string_join::StringJoin;
+pub use string_join_properties::StringJoinProperties;
+
+pub mod function;
+pub mod string_join;
+pub mod string_join_properties;
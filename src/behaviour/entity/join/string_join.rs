@@ -0,0 +1,129 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::join::string_join_properties::StringJoinProperties;
+use crate::behaviour::entity::join::StringJoinFunction;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::expression::{Expression, ExpressionValue, OperatorPosition};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+pub type StringJoinExpressionValue = ExpressionValue<Value>;
+
+/// Generic implementation of joins with an array input (LHS), a separator and a string result.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringJoin<'a> {
+    pub lhs: RwLock<Stream<'a, StringJoinExpressionValue>>,
+
+    pub separator: RwLock<Stream<'a, StringJoinExpressionValue>>,
+
+    pub f: StringJoinFunction,
+
+    pub internal_result: RwLock<Stream<'a, String>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringJoin<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringJoinFunction) -> StringJoin<'static> {
+        let lhs = e
+            .properties
+            .get(StringJoinProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringJoinExpressionValue { (OperatorPosition::LHS, v.clone()) });
+        let separator = e
+            .properties
+            .get(StringJoinProperties::SEPARATOR.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringJoinExpressionValue { (OperatorPosition::RHS, v.clone()) });
+
+        let expression = lhs.merge(&separator).fold(
+            Expression::new(
+                e.get(StringJoinProperties::LHS.as_ref())
+                    .unwrap_or_else(|| StringJoinProperties::LHS.default_value()),
+                e.get(StringJoinProperties::SEPARATOR.as_ref())
+                    .unwrap_or_else(|| StringJoinProperties::SEPARATOR.default_value()),
+            ),
+            |old_state, (o, value)| match *o {
+                OperatorPosition::LHS => old_state.lhs(value.clone()),
+                OperatorPosition::RHS => old_state.rhs(value.clone()),
+            },
+        );
+
+        // The internal result
+        let internal_result = expression.map(move |e| {
+            let lhs = match &e.lhs {
+                Value::Array(elements) => elements.clone(),
+                Value::Null => Vec::new(),
+                element => vec![element.clone()],
+            };
+            f(lhs, e.rhs.as_str().map(String::from).unwrap_or_default())
+        });
+
+        let handle_id = e.properties.get(StringJoinProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_join = StringJoin {
+            lhs: RwLock::new(lhs),
+            separator: RwLock::new(separator),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_join.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string join: {}", v);
+                e.set(StringJoinProperties::RESULT.to_string(), json!(*v));
+            },
+            handle_id,
+        );
+
+        string_join
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringJoin<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string join {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringJoin<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringJoinProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringJoinProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringJoin<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string join");
+        self.disconnect();
+    }
+}
@@ -0,0 +1,48 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringJoinProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "separator")]
+    SEPARATOR,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringJoinProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringJoinProperties::LHS => json!([]),
+            StringJoinProperties::SEPARATOR => json!(""),
+            StringJoinProperties::RESULT => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringJoinProperties::LHS),
+            NamedProperty::from(StringJoinProperties::SEPARATOR),
+            NamedProperty::from(StringJoinProperties::RESULT),
+        ]
+    }
+}
+
+impl From<StringJoinProperties> for NamedProperty {
+    fn from(p: StringJoinProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringJoinProperties> for String {
+    fn from(p: StringJoinProperties) -> Self {
+        p.to_string()
+    }
+}
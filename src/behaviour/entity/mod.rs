@@ -1,4 +1,29 @@
+pub mod array_comparison;
+pub mod array_gate;
+pub mod array_operation;
 pub mod comparison;
 pub mod entity_behaviour_provider;
+pub mod fallible_operation;
 pub mod gate;
+pub mod hash;
+pub mod insert;
+pub mod join;
+pub mod number_format;
+pub mod number_gate;
+pub mod number_operation;
 pub mod operation;
+pub mod pad;
+pub mod parse;
+pub mod predicate;
+pub mod regex;
+pub mod repeat;
+pub mod replace;
+pub mod similar;
+pub mod similarity;
+pub mod slice;
+pub mod stringify;
+pub mod template;
+pub mod validator;
+pub mod value;
+pub mod value_operation;
+pub mod variadic_gate;
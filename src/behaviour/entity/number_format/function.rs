@@ -0,0 +1,81 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+
+/// The options of a number format.
+#[derive(Debug, Clone)]
+pub struct NumberFormat {
+    /// The number of decimal places. If None, the number is formatted with as many decimal places as needed.
+    pub precision: Option<usize>,
+    /// The minimum width of the result. Shorter results are right-aligned with spaces.
+    pub width: usize,
+    /// Separates groups of three digits of the integer part. Empty for no grouping.
+    pub thousands_separator: String,
+    /// If true, positive numbers are prefixed with a plus sign.
+    pub sign: bool,
+}
+
+/// The maximum number of decimal places supported by the formatting machinery of the standard library.
+pub const MAX_PRECISION: usize = u16::MAX as usize;
+
+/// Formats a number using the given options. The third argument is the maximum length of the result in bytes.
+pub type StringNumberFormatFunction = fn(f64, &NumberFormat, usize) -> Result<String, String>;
+
+pub const FN_FORMAT_NUMBER: StringNumberFormatFunction = |value, format, max_result_length| {
+    if format.width > max_result_length {
+        return Err(format!("The width {} exceeds the maximum result length of {} bytes", format.width, max_result_length));
+    }
+    let digits = match format.precision {
+        Some(precision) if precision > MAX_PRECISION => {
+            return Err(format!("The precision {} exceeds the maximum of {}", precision, MAX_PRECISION));
+        }
+        Some(precision) => format!("{:.*}", precision, value.abs()),
+        None => value.abs().to_string(),
+    };
+    let (integer, fraction) = match digits.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (digits.as_str(), None),
+    };
+    // Numbers which are rounded to zero are not negative
+    let sign = if value < 0.0 && digits.chars().any(|c| c.is_ascii_digit() && c != '0') {
+        "-"
+    } else if format.sign {
+        "+"
+    } else {
+        ""
+    };
+    let separators = integer.len().saturating_sub(1) / 3;
+    match separators
+        .checked_mul(format.thousands_separator.len())
+        .and_then(|length| length.checked_add(digits.len() + sign.len()))
+    {
+        Some(length) if length <= max_result_length => {}
+        _ => return Err(format!("The formatted number exceeds the maximum result length of {} bytes", max_result_length)),
+    }
+    let mut result = format!("{}{}", sign, group_digits(integer, format.thousands_separator.as_str()));
+    if let Some(fraction) = fraction {
+        result = format!("{}.{}", result, fraction);
+    }
+    // Right-align within the width, which is counted in characters
+    let padding = format.width.saturating_sub(result.chars().count());
+    Ok(format!("{}{}", " ".repeat(padding), result))
+};
+
+lazy_static! {
+    pub static ref STRING_NUMBER_FORMATS: HashMap<&'static str, StringNumberFormatFunction> = vec![("format_number", FN_FORMAT_NUMBER)].into_iter().collect();
+}
+
+/// Inserts the separator between groups of three digits, counted from the right.
+fn group_digits(digits: &str, separator: &str) -> String {
+    if separator.is_empty() {
+        return String::from(digits);
+    }
+    let mut result = String::new();
+    for (i, digit) in digits.chars().enumerate() {
+        if i > 0 && (digits.len() - i) % 3 == 0 {
+            result.push_str(separator);
+        }
+        result.push(digit);
+    }
+    result
+}
@@ -0,0 +1,9 @@
+pub use function::NumberFormat;
+pub use function::StringNumberFormatFunction;
+pub use function::STRING_NUMBER_FORMATS;
+pub use string_number_format::StringNumberFormat;
+pub use string_number_format_properties::StringNumberFormatProperties;
+
+pub mod function;
+pub mod string_number_format;
+pub mod string_number_format_properties;
@@ -0,0 +1,229 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::number_format::string_number_format_properties::StringNumberFormatProperties;
+use crate::behaviour::entity::number_format::{NumberFormat, StringNumberFormatFunction};
+use crate::behaviour::entity::value::{to_index, to_size};
+use crate::config::get_config;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::Disconnectable;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone)]
+pub enum StringNumberFormatPosition {
+    VALUE,
+    PRECISION,
+    WIDTH,
+    THOUSANDS_SEPARATOR,
+    SIGN,
+}
+
+pub type StringNumberFormatExpressionValue = (StringNumberFormatPosition, Value);
+
+/// The state of the inputs of a number format.
+#[derive(Debug, Clone)]
+pub struct StringNumberFormatExpression {
+    pub value: Value,
+    pub format: NumberFormat,
+}
+
+impl StringNumberFormatExpression {
+    /// Initializes the expression with the current values of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance) -> Self {
+        let get = |property: StringNumberFormatProperties| e.get(property.as_ref()).unwrap_or_else(|| property.default_value());
+        StringNumberFormatExpression {
+            value: get(StringNumberFormatProperties::VALUE),
+            format: NumberFormat {
+                precision: to_precision(&get(StringNumberFormatProperties::PRECISION)),
+                width: to_size(&get(StringNumberFormatProperties::WIDTH)),
+                thousands_separator: to_separator(&get(StringNumberFormatProperties::THOUSANDS_SEPARATOR)),
+                sign: get(StringNumberFormatProperties::SIGN).as_bool().unwrap_or_default(),
+            },
+        }
+    }
+
+    pub fn set(self, position: StringNumberFormatPosition, value: &Value) -> Self {
+        let format = self.format;
+        match position {
+            StringNumberFormatPosition::VALUE => StringNumberFormatExpression { value: value.clone(), format },
+            StringNumberFormatPosition::PRECISION => StringNumberFormatExpression {
+                format: NumberFormat {
+                    precision: to_precision(value),
+                    ..format
+                },
+                ..self
+            },
+            StringNumberFormatPosition::WIDTH => StringNumberFormatExpression {
+                format: NumberFormat {
+                    width: to_size(value),
+                    ..format
+                },
+                ..self
+            },
+            StringNumberFormatPosition::THOUSANDS_SEPARATOR => StringNumberFormatExpression {
+                format: NumberFormat {
+                    thousands_separator: to_separator(value),
+                    ..format
+                },
+                ..self
+            },
+            StringNumberFormatPosition::SIGN => StringNumberFormatExpression {
+                format: NumberFormat {
+                    sign: value.as_bool().unwrap_or_default(),
+                    ..format
+                },
+                ..self
+            },
+        }
+    }
+}
+
+/// Negative or missing precisions format the number with as many decimal places as needed.
+fn to_precision(value: &Value) -> Option<usize> {
+    to_index(value).filter(|precision| *precision >= 0).map(|precision| precision as usize)
+}
+
+fn to_separator(value: &Value) -> String {
+    value.as_str().map(String::from).unwrap_or_default()
+}
+
+/// Generic implementation of number formats with the number (VALUE), the format options (PRECISION, WIDTH,
+/// THOUSANDS_SEPARATOR, SIGN) and one result. Values which are not a number and options which would exceed the
+/// maximum result length don't update the result but are reported on the error output.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringNumberFormat<'a> {
+    pub value: RwLock<Stream<'a, StringNumberFormatExpressionValue>>,
+
+    pub precision: RwLock<Stream<'a, StringNumberFormatExpressionValue>>,
+
+    pub width: RwLock<Stream<'a, StringNumberFormatExpressionValue>>,
+
+    pub thousands_separator: RwLock<Stream<'a, StringNumberFormatExpressionValue>>,
+
+    pub sign: RwLock<Stream<'a, StringNumberFormatExpressionValue>>,
+
+    pub f: StringNumberFormatFunction,
+
+    pub internal_result: RwLock<Stream<'a, Result<String, String>>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringNumberFormat<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringNumberFormatFunction) -> StringNumberFormat<'static> {
+        let value = e
+            .properties
+            .get(StringNumberFormatProperties::VALUE.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringNumberFormatExpressionValue { (StringNumberFormatPosition::VALUE, v.clone()) });
+        let precision = e
+            .properties
+            .get(StringNumberFormatProperties::PRECISION.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringNumberFormatExpressionValue { (StringNumberFormatPosition::PRECISION, v.clone()) });
+        let width = e
+            .properties
+            .get(StringNumberFormatProperties::WIDTH.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringNumberFormatExpressionValue { (StringNumberFormatPosition::WIDTH, v.clone()) });
+        let thousands_separator = e
+            .properties
+            .get(StringNumberFormatProperties::THOUSANDS_SEPARATOR.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringNumberFormatExpressionValue { (StringNumberFormatPosition::THOUSANDS_SEPARATOR, v.clone()) });
+        let sign = e
+            .properties
+            .get(StringNumberFormatProperties::SIGN.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringNumberFormatExpressionValue { (StringNumberFormatPosition::SIGN, v.clone()) });
+
+        let expression = value
+            .merge(&precision)
+            .merge(&width)
+            .merge(&thousands_separator)
+            .merge(&sign)
+            .fold(StringNumberFormatExpression::new(&e), |old_state, (o, value)| old_state.set(*o, value));
+
+        // The internal result
+        let max_result_length = get_config().max_result_length;
+        let internal_result = expression.map(move |e| match e.value.as_f64() {
+            Some(value) => f(value, &e.format, max_result_length),
+            None => Err(String::from("The value is not a number")),
+        });
+
+        let handle_id = e.properties.get(StringNumberFormatProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_number_format = StringNumberFormat {
+            value: RwLock::new(value),
+            precision: RwLock::new(precision),
+            width: RwLock::new(width),
+            thousands_separator: RwLock::new(thousands_separator),
+            sign: RwLock::new(sign),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_number_format.internal_result.read().unwrap().observe_with_handle(
+            move |v| match v {
+                Ok(result) => {
+                    debug!("Setting result of string number format: {}", result);
+                    e.set(StringNumberFormatProperties::ERROR.to_string(), json!(""));
+                    e.set(StringNumberFormatProperties::RESULT.to_string(), json!(result));
+                }
+                Err(error) => {
+                    debug!("Setting error of string number format: {}", error);
+                    e.set(StringNumberFormatProperties::ERROR.to_string(), json!(error));
+                }
+            },
+            handle_id,
+        );
+
+        string_number_format
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringNumberFormat<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string number format {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringNumberFormat<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string number format");
+        self.disconnect();
+    }
+}
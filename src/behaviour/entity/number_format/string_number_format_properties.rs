@@ -0,0 +1,65 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringNumberFormatProperties {
+    #[strum(serialize = "value")]
+    VALUE,
+    /// A negative precision formats the number with as many decimal places as needed
+    #[strum(serialize = "precision")]
+    PRECISION,
+    #[strum(serialize = "width")]
+    WIDTH,
+    #[strum(serialize = "thousands_separator")]
+    THOUSANDS_SEPARATOR,
+    #[strum(serialize = "sign")]
+    SIGN,
+    #[strum(serialize = "result")]
+    RESULT,
+    #[strum(serialize = "error")]
+    ERROR,
+}
+
+impl StringNumberFormatProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringNumberFormatProperties::VALUE => json!(0),
+            StringNumberFormatProperties::PRECISION => json!(-1),
+            StringNumberFormatProperties::WIDTH => json!(0),
+            StringNumberFormatProperties::THOUSANDS_SEPARATOR => json!(""),
+            StringNumberFormatProperties::SIGN => json!(false),
+            StringNumberFormatProperties::RESULT => json!(""),
+            StringNumberFormatProperties::ERROR => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringNumberFormatProperties::VALUE),
+            NamedProperty::from(StringNumberFormatProperties::PRECISION),
+            NamedProperty::from(StringNumberFormatProperties::WIDTH),
+            NamedProperty::from(StringNumberFormatProperties::THOUSANDS_SEPARATOR),
+            NamedProperty::from(StringNumberFormatProperties::SIGN),
+            NamedProperty::from(StringNumberFormatProperties::RESULT),
+            NamedProperty::from(StringNumberFormatProperties::ERROR),
+        ]
+    }
+}
+
+impl From<StringNumberFormatProperties> for NamedProperty {
+    fn from(p: StringNumberFormatProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringNumberFormatProperties> for String {
+    fn from(p: StringNumberFormatProperties) -> Self {
+        p.to_string()
+    }
+}
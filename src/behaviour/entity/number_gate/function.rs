@@ -0,0 +1,53 @@
+use lazy_static::lazy_static;
+use std::cmp::Ordering;
+use std::collections::HashMap;
+
+/// Computes a number from the two strings lhs and rhs. The third parameter n is only used by find_nth.
+pub type StringNumberGateFunction = fn(String, String, i64) -> i64;
+
+/// Returns the char position of the first occurrence of rhs in lhs or -1 if rhs was not found.
+pub const FN_FIND: StringNumberGateFunction = |lhs, rhs, _| to_char_position(lhs.as_str(), lhs.find(rhs.as_str()));
+/// Returns the char position of the last occurrence of rhs in lhs or -1 if rhs was not found.
+pub const FN_RFIND: StringNumberGateFunction = |lhs, rhs, _| to_char_position(lhs.as_str(), lhs.rfind(rhs.as_str()));
+/// Returns the char position of the n-th (zero based) non-overlapping occurrence of rhs in lhs or -1 if there are less
+/// occurrences or n is negative.
+pub const FN_FIND_NTH: StringNumberGateFunction = |lhs, rhs, n| {
+    if n < 0 {
+        return -1;
+    }
+    let byte_position = lhs.match_indices(rhs.as_str()).nth(n as usize).map(|(byte_position, _)| byte_position);
+    to_char_position(lhs.as_str(), byte_position)
+};
+/// Returns -1 if lhs is lexicographically less than rhs, 1 if lhs is greater than rhs and 0 if both are equal.
+pub const FN_COMPARE: StringNumberGateFunction = |lhs, rhs, _| to_number(lhs.cmp(&rhs));
+/// Like compare, but sequences of digits are compared by their numeric value ("map9" < "map10").
+pub const FN_NATURAL_COMPARE: StringNumberGateFunction = |lhs, rhs, _| to_number(natord::compare(lhs.as_str(), rhs.as_str()));
+
+lazy_static! {
+    pub static ref STRING_NUMBER_GATES: HashMap<&'static str, StringNumberGateFunction> = vec![
+        ("find", FN_FIND),
+        ("rfind", FN_RFIND),
+        ("find_nth", FN_FIND_NTH),
+        ("compare", FN_COMPARE),
+        ("natural_compare", FN_NATURAL_COMPARE),
+    ]
+    .into_iter()
+    .collect();
+}
+
+/// Converts a byte position into a char position. Returns -1 if there is no position.
+pub fn to_char_position(s: &str, byte_position: Option<usize>) -> i64 {
+    match byte_position {
+        Some(byte_position) => s[..byte_position].chars().count() as i64,
+        None => -1,
+    }
+}
+
+/// Converts an ordering into -1, 0 or 1.
+pub fn to_number(ordering: Ordering) -> i64 {
+    match ordering {
+        Ordering::Less => -1,
+        Ordering::Equal => 0,
+        Ordering::Greater => 1,
+    }
+}
@@ -0,0 +1,8 @@
+pub use function::StringNumberGateFunction;
+pub use function::STRING_NUMBER_GATES;
+pub use string_number_gate::StringNumberGate;
+pub use string_number_gate_properties::StringNumberGateProperties;
+
+pub mod function;
+pub mod string_number_gate;
+pub mod string_number_gate_properties;
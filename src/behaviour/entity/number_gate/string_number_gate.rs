@@ -0,0 +1,183 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::number_gate::string_number_gate_properties::StringNumberGateProperties;
+use crate::behaviour::entity::number_gate::StringNumberGateFunction;
+use crate::behaviour::entity::value::to_index;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::gate::Gate;
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+#[derive(Debug, Copy, Clone)]
+pub enum StringNumberGatePosition {
+    LHS,
+    RHS,
+    N,
+}
+
+pub type StringNumberGateExpressionValue = (StringNumberGatePosition, Value);
+
+/// The state of the inputs of a string number gate.
+#[derive(Debug, Clone)]
+pub struct StringNumberGateExpression {
+    pub lhs: String,
+    pub rhs: String,
+    pub n: i64,
+}
+
+impl StringNumberGateExpression {
+    /// Initializes the expression with the current values of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance) -> Self {
+        StringNumberGateExpression {
+            lhs: e
+                .get(StringNumberGateProperties::LHS.as_ref())
+                .and_then(|v| v.as_str().map(String::from))
+                .unwrap_or_default(),
+            rhs: e
+                .get(StringNumberGateProperties::RHS.as_ref())
+                .and_then(|v| v.as_str().map(String::from))
+                .unwrap_or_default(),
+            n: e.get(StringNumberGateProperties::N.as_ref()).and_then(|v| to_index(&v)).unwrap_or_default(),
+        }
+    }
+
+    pub fn set(self, position: StringNumberGatePosition, value: &Value) -> Self {
+        match position {
+            StringNumberGatePosition::LHS => StringNumberGateExpression {
+                lhs: value.as_str().map(String::from).unwrap_or_default(),
+                ..self
+            },
+            StringNumberGatePosition::RHS => StringNumberGateExpression {
+                rhs: value.as_str().map(String::from).unwrap_or_default(),
+                ..self
+            },
+            StringNumberGatePosition::N => StringNumberGateExpression {
+                n: to_index(value).unwrap_or_default(),
+                ..self
+            },
+        }
+    }
+}
+
+/// Generic implementation of string gates with two string inputs (LHS,RHS) and a numeric result.
+///
+/// Entity types which select one of multiple occurrences additionally provide the input N.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringNumberGate<'a> {
+    pub lhs: RwLock<Stream<'a, StringNumberGateExpressionValue>>,
+
+    pub rhs: RwLock<Stream<'a, StringNumberGateExpressionValue>>,
+
+    pub n: Option<RwLock<Stream<'a, StringNumberGateExpressionValue>>>,
+
+    pub f: StringNumberGateFunction,
+
+    pub internal_result: RwLock<Stream<'a, i64>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringNumberGate<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringNumberGateFunction) -> StringNumberGate<'static> {
+        let lhs = e
+            .properties
+            .get(StringNumberGateProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringNumberGateExpressionValue { (StringNumberGatePosition::LHS, v.clone()) });
+        let rhs = e
+            .properties
+            .get(StringNumberGateProperties::RHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringNumberGateExpressionValue { (StringNumberGatePosition::RHS, v.clone()) });
+        let n = e.properties.get(StringNumberGateProperties::N.as_ref()).map(|property| {
+            property
+                .stream
+                .read()
+                .unwrap()
+                .map(|v| -> StringNumberGateExpressionValue { (StringNumberGatePosition::N, v.clone()) })
+        });
+
+        let mut inputs = lhs.merge(&rhs);
+        if let Some(n) = &n {
+            inputs = inputs.merge(n);
+        }
+        let expression = inputs.fold(StringNumberGateExpression::new(&e), |old_state, (o, value)| old_state.set(*o, value));
+
+        // The internal result
+        let internal_result = expression.map(move |e| f(e.lhs.clone(), e.rhs.clone(), e.n));
+
+        let handle_id = e.properties.get(StringNumberGateProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_number_gate = StringNumberGate {
+            lhs: RwLock::new(lhs),
+            rhs: RwLock::new(rhs),
+            n: n.map(RwLock::new),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_number_gate.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string number gate: {}", v);
+                e.set(StringNumberGateProperties::RESULT.to_string(), json!(*v));
+            },
+            handle_id,
+        );
+
+        string_number_gate
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringNumberGate<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string number gate {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringNumberGate<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringNumberGateProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringNumberGateProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+impl Gate for StringNumberGate<'_> {
+    fn rhs(&self, value: Value) {
+        self.entity.set(StringNumberGateProperties::RHS.as_ref(), value);
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringNumberGate<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string number gate");
+        self.disconnect();
+    }
+}
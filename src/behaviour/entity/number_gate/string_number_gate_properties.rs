@@ -0,0 +1,52 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringNumberGateProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "rhs")]
+    RHS,
+    /// Only available on entity types which select one of multiple occurrences (find_nth)
+    #[strum(serialize = "n")]
+    N,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringNumberGateProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringNumberGateProperties::LHS => json!(""),
+            StringNumberGateProperties::RHS => json!(""),
+            StringNumberGateProperties::N => json!(0),
+            StringNumberGateProperties::RESULT => json!(-1),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringNumberGateProperties::LHS),
+            NamedProperty::from(StringNumberGateProperties::RHS),
+            NamedProperty::from(StringNumberGateProperties::RESULT),
+        ]
+    }
+}
+
+impl From<StringNumberGateProperties> for NamedProperty {
+    fn from(p: StringNumberGateProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringNumberGateProperties> for String {
+    fn from(p: StringNumberGateProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -0,0 +1,24 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
+
+pub type StringNumberOperationFunction = fn(String) -> usize;
+
+pub const FN_BYTE_LENGTH: StringNumberOperationFunction = |lhs: String| lhs.len();
+pub const FN_CHAR_COUNT: StringNumberOperationFunction = |lhs: String| lhs.chars().count();
+pub const FN_GRAPHEME_COUNT: StringNumberOperationFunction = |lhs: String| lhs.graphemes(true).count();
+pub const FN_WORD_COUNT: StringNumberOperationFunction = |lhs: String| lhs.unicode_words().count();
+pub const FN_LINE_COUNT: StringNumberOperationFunction = |lhs: String| lhs.lines().count();
+
+lazy_static! {
+    pub static ref STRING_NUMBER_OPERATIONS: HashMap<&'static str, StringNumberOperationFunction> = vec![
+        ("length", FN_GRAPHEME_COUNT),
+        ("byte_length", FN_BYTE_LENGTH),
+        ("char_count", FN_CHAR_COUNT),
+        ("grapheme_count", FN_GRAPHEME_COUNT),
+        ("word_count", FN_WORD_COUNT),
+        ("line_count", FN_LINE_COUNT),
+    ]
+    .into_iter()
+    .collect();
+}
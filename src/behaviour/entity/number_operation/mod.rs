@@ -0,0 +1,8 @@
+pub use function::StringNumberOperationFunction;
+pub use function::STRING_NUMBER_OPERATIONS;
+pub use string_number_operation::StringNumberOperation;
+pub use string_number_operation_properties::StringNumberOperationProperties;
+
+pub mod function;
+pub mod string_number_operation;
+pub mod string_number_operation_properties;
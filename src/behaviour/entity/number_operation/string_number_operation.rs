@@ -0,0 +1,92 @@
+use std::convert::AsRef;
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::number_operation::string_number_operation_properties::StringNumberOperationProperties;
+use crate::behaviour::entity::number_operation::StringNumberOperationFunction;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+/// Generic implementation of string operations with one string input and one numeric result.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringNumberOperation<'a> {
+    pub f: StringNumberOperationFunction,
+
+    pub internal_result: RwLock<Stream<'a, Value>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringNumberOperation<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringNumberOperationFunction) -> StringNumberOperation<'static> {
+        let handle_id = e.properties.get(StringNumberOperationProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let internal_result = e
+            .properties
+            .get(StringNumberOperationProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(move |v| match v.as_str() {
+                Some(lhs_str) => json!(f(String::from(lhs_str))),
+                None => StringNumberOperationProperties::RESULT.default_value(),
+            });
+        let string_number_operation = StringNumberOperation {
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_number_operation.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string number operation: {}", v);
+                e.set(StringNumberOperationProperties::RESULT.to_string(), v.clone());
+            },
+            handle_id,
+        );
+
+        string_number_operation
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringNumberOperation<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string number operation {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringNumberOperation<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringNumberOperationProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringNumberOperationProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringNumberOperation<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string number operation");
+        self.disconnect();
+    }
+}
@@ -0,0 +1,44 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringNumberOperationProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringNumberOperationProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringNumberOperationProperties::LHS => json!(""),
+            StringNumberOperationProperties::RESULT => json!(0),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringNumberOperationProperties::LHS),
+            NamedProperty::from(StringNumberOperationProperties::RESULT),
+        ]
+    }
+}
+
+impl From<StringNumberOperationProperties> for NamedProperty {
+    fn from(p: StringNumberOperationProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringNumberOperationProperties> for String {
+    fn from(p: StringNumberOperationProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -1,5 +1,9 @@
+use caseless::default_case_fold_str;
+use heck::{ToKebabCase, ToLowerCamelCase, ToShoutySnakeCase, ToSnakeCase, ToTitleCase, ToUpperCamelCase};
 use lazy_static::lazy_static;
+use percent_encoding::{utf8_percent_encode, AsciiSet, NON_ALPHANUMERIC};
 use std::collections::HashMap;
+use unicode_normalization::UnicodeNormalization;
 
 pub type StringOperationFunction = fn(String) -> String;
 
@@ -9,6 +13,35 @@ pub const FN_TRIM_END: StringOperationFunction = |lhs: String| String::from(lhs.
 pub const FN_UPPERCASE: StringOperationFunction = |lhs: String| lhs.to_uppercase();
 pub const FN_LOWERCASE: StringOperationFunction = |lhs: String| lhs.to_lowercase();
 
+// Identifier case conversions. Word boundaries are detected at whitespace, punctuation and case changes. Acronyms are
+// kept together ("XMLHttpRequest" -> "xml_http_request") and digits belong to the preceding word ("utf8String" ->
+// "utf8_string").
+pub const FN_SNAKE_CASE: StringOperationFunction = |lhs: String| lhs.to_snake_case();
+pub const FN_CAMEL_CASE: StringOperationFunction = |lhs: String| lhs.to_lower_camel_case();
+pub const FN_PASCAL_CASE: StringOperationFunction = |lhs: String| lhs.to_upper_camel_case();
+pub const FN_KEBAB_CASE: StringOperationFunction = |lhs: String| lhs.to_kebab_case();
+pub const FN_TITLE_CASE: StringOperationFunction = |lhs: String| lhs.to_title_case();
+pub const FN_SCREAMING_SNAKE_CASE: StringOperationFunction = |lhs: String| lhs.to_shouty_snake_case();
+
+// Unicode normalization forms (https://unicode.org/reports/tr15/). The Unicode tables are compiled into the plugin.
+pub const FN_NFC: StringOperationFunction = |lhs: String| lhs.nfc().collect();
+pub const FN_NFD: StringOperationFunction = |lhs: String| lhs.nfd().collect();
+pub const FN_NFKC: StringOperationFunction = |lhs: String| lhs.nfkc().collect();
+pub const FN_NFKD: StringOperationFunction = |lhs: String| lhs.nfkd().collect();
+/// Full case folding for caseless matching ("Straße" -> "strasse"). Unlike lowercase, the result is meant for comparisons only.
+pub const FN_CASEFOLD: StringOperationFunction = |lhs: String| default_case_fold_str(lhs.as_str());
+
+// Encoders. The corresponding decoders can fail and are fallible operations.
+pub const FN_BASE64_ENCODE: StringOperationFunction = |lhs: String| base64::encode(lhs);
+pub const FN_BASE64URL_ENCODE: StringOperationFunction = |lhs: String| base64::encode_config(lhs, base64::URL_SAFE_NO_PAD);
+pub const FN_HEX_ENCODE: StringOperationFunction = |lhs: String| hex::encode(lhs);
+pub const FN_PERCENT_ENCODE: StringOperationFunction = |lhs: String| utf8_percent_encode(lhs.as_str(), PERCENT_ENCODE_SET).to_string();
+pub const FN_HTML_ESCAPE: StringOperationFunction = |lhs: String| html_escape::encode_safe(lhs.as_str()).into_owned();
+pub const FN_HTML_UNESCAPE: StringOperationFunction = |lhs: String| html_escape::decode_html_entities(lhs.as_str()).into_owned();
+
+/// Percent-encodes everything except the unreserved characters of RFC 3986.
+const PERCENT_ENCODE_SET: &AsciiSet = &NON_ALPHANUMERIC.remove(b'-').remove(b'.').remove(b'_').remove(b'~');
+
 lazy_static! {
     pub static ref STRING_OPERATIONS: HashMap<&'static str, StringOperationFunction> = vec![
         ("trim", FN_TRIM),
@@ -16,6 +49,23 @@ lazy_static! {
         ("trim_end", FN_TRIM_END),
         ("uppercase", FN_UPPERCASE),
         ("lowercase", FN_LOWERCASE),
+        ("snake_case", FN_SNAKE_CASE),
+        ("camel_case", FN_CAMEL_CASE),
+        ("pascal_case", FN_PASCAL_CASE),
+        ("kebab_case", FN_KEBAB_CASE),
+        ("title_case", FN_TITLE_CASE),
+        ("screaming_snake_case", FN_SCREAMING_SNAKE_CASE),
+        ("nfc", FN_NFC),
+        ("nfd", FN_NFD),
+        ("nfkc", FN_NFKC),
+        ("nfkd", FN_NFKD),
+        ("casefold", FN_CASEFOLD),
+        ("base64_encode", FN_BASE64_ENCODE),
+        ("base64url_encode", FN_BASE64URL_ENCODE),
+        ("hex_encode", FN_HEX_ENCODE),
+        ("percent_encode", FN_PERCENT_ENCODE),
+        ("html_escape", FN_HTML_ESCAPE),
+        ("html_unescape", FN_HTML_UNESCAPE),
     ]
     .into_iter()
     .collect();
@@ -0,0 +1,48 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// Pads lhs with the fill character up to the given width.
+///
+/// The width is counted in grapheme clusters (user-perceived characters). The first grapheme cluster of fill is used
+/// as fill character, an empty fill pads with spaces. Strings which are already wider are returned unchanged. The
+/// fourth argument is the maximum length of the result in bytes.
+pub type StringPadFunction = fn(String, usize, String, usize) -> Result<String, String>;
+
+pub const FN_PAD_START: StringPadFunction = |lhs, width, fill, max_result_length| {
+    let (fill, padding) = padding(lhs.as_str(), width, fill.as_str(), max_result_length)?;
+    Ok(format!("{}{}", fill.repeat(padding), lhs))
+};
+pub const FN_PAD_END: StringPadFunction = |lhs, width, fill, max_result_length| {
+    let (fill, padding) = padding(lhs.as_str(), width, fill.as_str(), max_result_length)?;
+    Ok(format!("{}{}", lhs, fill.repeat(padding)))
+};
+/// Centers lhs. If the padding can't be split evenly, the additional fill character is put at the end.
+pub const FN_CENTER: StringPadFunction = |lhs, width, fill, max_result_length| {
+    let (fill, padding) = padding(lhs.as_str(), width, fill.as_str(), max_result_length)?;
+    let start = padding / 2;
+    Ok(format!("{}{}{}", fill.repeat(start), lhs, fill.repeat(padding - start)))
+};
+
+lazy_static! {
+    pub static ref STRING_PADS: HashMap<&'static str, StringPadFunction> = vec![("pad_start", FN_PAD_START), ("pad_end", FN_PAD_END), ("center", FN_CENTER),]
+        .into_iter()
+        .collect();
+}
+
+/// Returns the fill character and the number of fill characters needed to reach the width.
+///
+/// Refuses paddings which would exceed the maximum result length instead of allocating them.
+fn padding<'a>(lhs: &str, width: usize, fill: &'a str, max_result_length: usize) -> Result<(&'a str, usize), String> {
+    let fill = fill.graphemes(true).next().unwrap_or(" ");
+    let padding = width.saturating_sub(lhs.graphemes(true).count());
+    match padding.checked_mul(fill.len()).and_then(|length| length.checked_add(lhs.len())) {
+        Some(length) if length <= max_result_length => Ok((fill, padding)),
+        _ => Err(format!(
+            "Padding {} bytes to a width of {} exceeds the maximum result length of {} bytes",
+            lhs.len(),
+            width,
+            max_result_length
+        )),
+    }
+}
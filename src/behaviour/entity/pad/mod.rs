@@ -0,0 +1,8 @@
+pub use function::StringPadFunction;
+pub use function::STRING_PADS;
+pub use string_pad::StringPad;
+pub use string_pad_properties::StringPadProperties;
+
+pub mod function;
+pub mod string_pad;
+pub mod string_pad_properties;
@@ -0,0 +1,181 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::pad::string_pad_properties::StringPadProperties;
+use crate::behaviour::entity::pad::StringPadFunction;
+use crate::behaviour::entity::value::to_size;
+use crate::config::get_config;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+#[derive(Debug, Copy, Clone)]
+pub enum StringPadPosition {
+    LHS,
+    WIDTH,
+    FILL,
+}
+
+pub type StringPadExpressionValue = (StringPadPosition, Value);
+
+/// The state of the inputs of a string pad.
+#[derive(Debug, Clone)]
+pub struct StringPadExpression {
+    pub lhs: String,
+    pub width: usize,
+    pub fill: String,
+}
+
+impl StringPadExpression {
+    /// Initializes the expression with the current values of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance) -> Self {
+        StringPadExpression {
+            lhs: e
+                .get(StringPadProperties::LHS.as_ref())
+                .and_then(|v| v.as_str().map(String::from))
+                .unwrap_or_default(),
+            width: e.get(StringPadProperties::WIDTH.as_ref()).map(|v| to_size(&v)).unwrap_or_default(),
+            fill: e
+                .get(StringPadProperties::FILL.as_ref())
+                .and_then(|v| v.as_str().map(String::from))
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn set(self, position: StringPadPosition, value: &Value) -> Self {
+        match position {
+            StringPadPosition::LHS => StringPadExpression {
+                lhs: value.as_str().map(String::from).unwrap_or_default(),
+                ..self
+            },
+            StringPadPosition::WIDTH => StringPadExpression { width: to_size(value), ..self },
+            StringPadPosition::FILL => StringPadExpression {
+                fill: value.as_str().map(String::from).unwrap_or_default(),
+                ..self
+            },
+        }
+    }
+}
+
+/// Generic implementation of string paddings with a string input (LHS), the width, the fill character and one result.
+/// Paddings which would exceed the maximum result length are reported on the error output.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringPad<'a> {
+    pub lhs: RwLock<Stream<'a, StringPadExpressionValue>>,
+
+    pub width: RwLock<Stream<'a, StringPadExpressionValue>>,
+
+    pub fill: RwLock<Stream<'a, StringPadExpressionValue>>,
+
+    pub f: StringPadFunction,
+
+    pub internal_result: RwLock<Stream<'a, Result<String, String>>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringPad<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringPadFunction) -> StringPad<'static> {
+        let lhs = e
+            .properties
+            .get(StringPadProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringPadExpressionValue { (StringPadPosition::LHS, v.clone()) });
+        let width = e
+            .properties
+            .get(StringPadProperties::WIDTH.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringPadExpressionValue { (StringPadPosition::WIDTH, v.clone()) });
+        let fill = e
+            .properties
+            .get(StringPadProperties::FILL.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringPadExpressionValue { (StringPadPosition::FILL, v.clone()) });
+
+        let expression = lhs
+            .merge(&width)
+            .merge(&fill)
+            .fold(StringPadExpression::new(&e), |old_state, (o, value)| old_state.set(*o, value));
+
+        // The internal result
+        let max_result_length = get_config().max_result_length;
+        let internal_result = expression.map(move |e| f(e.lhs.clone(), e.width, e.fill.clone(), max_result_length));
+
+        let handle_id = e.properties.get(StringPadProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_pad = StringPad {
+            lhs: RwLock::new(lhs),
+            width: RwLock::new(width),
+            fill: RwLock::new(fill),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_pad.internal_result.read().unwrap().observe_with_handle(
+            move |v| match v {
+                Ok(result) => {
+                    debug!("Setting result of string pad");
+                    e.set(StringPadProperties::ERROR.to_string(), json!(""));
+                    e.set(StringPadProperties::RESULT.to_string(), json!(result));
+                }
+                Err(error) => {
+                    debug!("Setting error of string pad: {}", error);
+                    e.set(StringPadProperties::ERROR.to_string(), json!(error));
+                }
+            },
+            handle_id,
+        );
+
+        string_pad
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringPad<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string pad {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringPad<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringPadProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringPadProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringPad<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string pad");
+        self.disconnect();
+    }
+}
@@ -0,0 +1,56 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringPadProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "width")]
+    WIDTH,
+    #[strum(serialize = "fill")]
+    FILL,
+    #[strum(serialize = "result")]
+    RESULT,
+    #[strum(serialize = "error")]
+    ERROR,
+}
+
+impl StringPadProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringPadProperties::LHS => json!(""),
+            StringPadProperties::WIDTH => json!(0),
+            StringPadProperties::FILL => json!(" "),
+            StringPadProperties::RESULT => json!(""),
+            StringPadProperties::ERROR => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringPadProperties::LHS),
+            NamedProperty::from(StringPadProperties::WIDTH),
+            NamedProperty::from(StringPadProperties::FILL),
+            NamedProperty::from(StringPadProperties::RESULT),
+            NamedProperty::from(StringPadProperties::ERROR),
+        ]
+    }
+}
+
+impl From<StringPadProperties> for NamedProperty {
+    fn from(p: StringPadProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringPadProperties> for String {
+    fn from(p: StringPadProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -0,0 +1,40 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use serde_json::{json, Value};
+
+/// Parses the string into a typed value. The second argument is the radix, which is only used by parse_int.
+pub type StringParseFunction = fn(String, u32) -> Result<Value, String>;
+
+/// Parses a floating point number. NaN and infinity are rejected because they can't be represented in JSON.
+pub const FN_PARSE_FLOAT: StringParseFunction = |lhs, _| match lhs.trim().parse::<f64>() {
+    Ok(number) if number.is_finite() => Ok(json!(number)),
+    Ok(_) => Err(format!("{} is not a finite number", lhs.trim())),
+    Err(e) => Err(e.to_string()),
+};
+/// Parses an integer in the given radix (2 to 36).
+pub const FN_PARSE_INT: StringParseFunction = |lhs, radix| {
+    if !(2..=36).contains(&radix) {
+        return Err(format!("The radix {} is not in the range 2 to 36", radix));
+    }
+    i64::from_str_radix(lhs.trim(), radix).map(|number| json!(number)).map_err(|e| e.to_string())
+};
+/// Parses a boolean. Accepts true/false, yes/no, on/off and 1/0 (case insensitive).
+pub const FN_PARSE_BOOL: StringParseFunction = |lhs, _| match lhs.trim().to_lowercase().as_str() {
+    "true" | "yes" | "on" | "1" => Ok(json!(true)),
+    "false" | "no" | "off" | "0" => Ok(json!(false)),
+    _ => Err(format!("{} is not a boolean", lhs.trim())),
+};
+/// Parses a JSON document into a value of any type.
+pub const FN_JSON_PARSE: StringParseFunction = |lhs, _| serde_json::from_str(lhs.as_str()).map_err(|e| e.to_string());
+
+lazy_static! {
+    pub static ref STRING_PARSES: HashMap<&'static str, StringParseFunction> = vec![
+        ("parse_float", FN_PARSE_FLOAT),
+        ("parse_int", FN_PARSE_INT),
+        ("parse_bool", FN_PARSE_BOOL),
+        ("json_parse", FN_JSON_PARSE),
+    ]
+    .into_iter()
+    .collect();
+}
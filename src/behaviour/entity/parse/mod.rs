@@ -0,0 +1,8 @@
+pub use function::StringParseFunction;
+pub use function::STRING_PARSES;
+pub use string_parse::StringParse;
+pub use string_parse_properties::StringParseProperties;
+
+pub mod function;
+pub mod string_parse;
+pub mod string_parse_properties;
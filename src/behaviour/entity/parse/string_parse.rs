@@ -0,0 +1,175 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::parse::string_parse_properties::StringParseProperties;
+use crate::behaviour::entity::parse::StringParseFunction;
+use crate::behaviour::entity::value::to_index;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+#[derive(Debug, Copy, Clone)]
+pub enum StringParsePosition {
+    LHS,
+    RADIX,
+}
+
+pub type StringParseExpressionValue = (StringParsePosition, Value);
+
+/// The state of the inputs of a string parse.
+#[derive(Debug, Clone)]
+pub struct StringParseExpression {
+    pub lhs: Value,
+    /// The radix or an error if the radix is not a number which fits into an u32.
+    pub radix: Result<u32, String>,
+}
+
+impl StringParseExpression {
+    /// Initializes the expression with the current values of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance) -> Self {
+        StringParseExpression {
+            lhs: e
+                .get(StringParseProperties::LHS.as_ref())
+                .unwrap_or_else(|| StringParseProperties::LHS.default_value()),
+            radix: to_radix(e.get(StringParseProperties::RADIX.as_ref())),
+        }
+    }
+
+    pub fn set(self, position: StringParsePosition, value: &Value) -> Self {
+        match position {
+            StringParsePosition::LHS => StringParseExpression { lhs: value.clone(), ..self },
+            StringParsePosition::RADIX => StringParseExpression {
+                radix: to_radix(Some(value.clone())),
+                ..self
+            },
+        }
+    }
+}
+
+/// Floating point radixes are truncated. Radixes which are not a number or out of the range of an u32 are errors,
+/// the range of valid radixes is checked by the parse function.
+fn to_radix(value: Option<Value>) -> Result<u32, String> {
+    let value = value.unwrap_or_else(|| StringParseProperties::RADIX.default_value());
+    to_index(&value)
+        .and_then(|radix| u32::try_from(radix).ok())
+        .ok_or_else(|| format!("The radix {} is not in the range 2 to 36", value))
+}
+
+/// Generic implementation of parsing a string into a typed value with one input (LHS) and one result.
+///
+/// Entity types which parse integers additionally provide the input RADIX. If parsing fails, valid is false, the error
+/// is reported on the error output and the result keeps its previous value.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringParse<'a> {
+    pub lhs: RwLock<Stream<'a, StringParseExpressionValue>>,
+
+    pub radix: Option<RwLock<Stream<'a, StringParseExpressionValue>>>,
+
+    pub f: StringParseFunction,
+
+    pub internal_result: RwLock<Stream<'a, Result<Value, String>>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringParse<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringParseFunction) -> StringParse<'static> {
+        let lhs = e
+            .properties
+            .get(StringParseProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringParseExpressionValue { (StringParsePosition::LHS, v.clone()) });
+        let radix = e.properties.get(StringParseProperties::RADIX.as_ref()).map(|property| {
+            property
+                .stream
+                .read()
+                .unwrap()
+                .map(|v| -> StringParseExpressionValue { (StringParsePosition::RADIX, v.clone()) })
+        });
+
+        let inputs = match &radix {
+            Some(radix) => lhs.merge(radix),
+            None => lhs.map(|v| v.clone()),
+        };
+        let expression = inputs.fold(StringParseExpression::new(&e), |old_state, (o, value)| old_state.set(*o, value));
+
+        // The internal result
+        let internal_result = expression.map(move |e| match (e.lhs.as_str(), &e.radix) {
+            (Some(lhs), Ok(radix)) => f(String::from(lhs), *radix),
+            (Some(_), Err(error)) => Err(error.clone()),
+            (None, _) => Err(String::from("The lhs is not a string")),
+        });
+
+        let handle_id = e.properties.get(StringParseProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_parse = StringParse {
+            lhs: RwLock::new(lhs),
+            radix: radix.map(RwLock::new),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_parse.internal_result.read().unwrap().observe_with_handle(
+            move |v| match v {
+                Ok(result) => {
+                    debug!("Setting result of string parse: {}", result);
+                    e.set(StringParseProperties::ERROR.to_string(), json!(""));
+                    e.set(StringParseProperties::VALID.to_string(), json!(true));
+                    e.set(StringParseProperties::RESULT.to_string(), result.clone());
+                }
+                Err(error) => {
+                    debug!("Setting error of string parse: {}", error);
+                    e.set(StringParseProperties::ERROR.to_string(), json!(error));
+                    e.set(StringParseProperties::VALID.to_string(), json!(false));
+                }
+            },
+            handle_id,
+        );
+
+        string_parse
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringParse<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string parse {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringParse<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringParseProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringParseProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringParse<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string parse");
+        self.disconnect();
+    }
+}
@@ -0,0 +1,57 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringParseProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    /// Only available on entity types which parse integers (parse_int)
+    #[strum(serialize = "radix")]
+    RADIX,
+    /// The data type of the result depends on the entity type
+    #[strum(serialize = "result")]
+    RESULT,
+    #[strum(serialize = "valid")]
+    VALID,
+    #[strum(serialize = "error")]
+    ERROR,
+}
+
+impl StringParseProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringParseProperties::LHS => json!(""),
+            StringParseProperties::RADIX => json!(10),
+            StringParseProperties::RESULT => Value::Null,
+            StringParseProperties::VALID => json!(false),
+            StringParseProperties::ERROR => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringParseProperties::LHS),
+            NamedProperty::from(StringParseProperties::RESULT),
+            NamedProperty::from(StringParseProperties::VALID),
+            NamedProperty::from(StringParseProperties::ERROR),
+        ]
+    }
+}
+
+impl From<StringParseProperties> for NamedProperty {
+    fn from(p: StringParseProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringParseProperties> for String {
+    fn from(p: StringParseProperties) -> Self {
+        p.to_string()
+    }
+}
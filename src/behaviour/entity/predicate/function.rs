@@ -0,0 +1,36 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+pub type StringPredicateFunction = fn(String) -> bool;
+
+pub const FN_IS_EMPTY: StringPredicateFunction = |lhs| lhs.is_empty();
+/// True if lhs is empty or contains only whitespace.
+pub const FN_IS_BLANK: StringPredicateFunction = |lhs| lhs.trim().is_empty();
+// The character class predicates are false for the empty string
+pub const FN_IS_NUMERIC: StringPredicateFunction = |lhs| all_chars(lhs.as_str(), char::is_numeric);
+pub const FN_IS_ALPHANUMERIC: StringPredicateFunction = |lhs| all_chars(lhs.as_str(), char::is_alphanumeric);
+pub const FN_IS_ALPHABETIC: StringPredicateFunction = |lhs| all_chars(lhs.as_str(), char::is_alphabetic);
+pub const FN_IS_ASCII: StringPredicateFunction = |lhs| lhs.is_ascii();
+/// True if lhs contains at least one cased character and no lowercase characters.
+pub const FN_IS_UPPERCASE: StringPredicateFunction = |lhs| lhs.chars().any(char::is_uppercase) && !lhs.chars().any(char::is_lowercase);
+/// True if lhs contains at least one cased character and no uppercase characters.
+pub const FN_IS_LOWERCASE: StringPredicateFunction = |lhs| lhs.chars().any(char::is_lowercase) && !lhs.chars().any(char::is_uppercase);
+
+lazy_static! {
+    pub static ref STRING_PREDICATES: HashMap<&'static str, StringPredicateFunction> = vec![
+        ("is_empty", FN_IS_EMPTY),
+        ("is_blank", FN_IS_BLANK),
+        ("is_numeric", FN_IS_NUMERIC),
+        ("is_alphanumeric", FN_IS_ALPHANUMERIC),
+        ("is_alphabetic", FN_IS_ALPHABETIC),
+        ("is_ascii", FN_IS_ASCII),
+        ("is_uppercase", FN_IS_UPPERCASE),
+        ("is_lowercase", FN_IS_LOWERCASE),
+    ]
+    .into_iter()
+    .collect();
+}
+
+fn all_chars(s: &str, predicate: fn(char) -> bool) -> bool {
+    !s.is_empty() && s.chars().all(predicate)
+}
@@ -0,0 +1,8 @@
+pub use function::StringPredicateFunction;
+pub use function::STRING_PREDICATES;
+pub use string_predicate::StringPredicate;
+pub use string_predicate_properties::StringPredicateProperties;
+
+pub mod function;
+pub mod string_predicate;
+pub mod string_predicate_properties;
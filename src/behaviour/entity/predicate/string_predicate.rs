@@ -0,0 +1,89 @@
+use std::convert::AsRef;
+use std::sync::{Arc, RwLock};
+
+use crate::behaviour::entity::predicate::string_predicate_properties::StringPredicateProperties;
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::predicate::StringPredicateFunction;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+/// Generic implementation of string predicates with one string input and one bool result.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringPredicate<'a> {
+    pub f: StringPredicateFunction,
+
+    pub internal_result: RwLock<Stream<'a, Value>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringPredicate<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringPredicateFunction) -> StringPredicate<'static> {
+        let handle_id = e.properties.get(StringPredicateProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let internal_result = e
+            .properties
+            .get(StringPredicateProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(move |v| json!(f(v.as_str().map(String::from).unwrap_or_default())));
+        let string_predicate = StringPredicate {
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_predicate.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string predicate: {}", v);
+                e.set(StringPredicateProperties::RESULT.to_string(), json!(*v));
+            },
+            handle_id,
+        );
+
+        string_predicate
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringPredicate<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string predicate {}", self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringPredicate<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringPredicateProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringPredicateProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringPredicate<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string predicate");
+        self.disconnect();
+    }
+}
@@ -0,0 +1,44 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringPredicateProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringPredicateProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringPredicateProperties::LHS => json!(""),
+            StringPredicateProperties::RESULT => json!(false),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringPredicateProperties::LHS),
+            NamedProperty::from(StringPredicateProperties::RESULT),
+        ]
+    }
+}
+
+impl From<StringPredicateProperties> for NamedProperty {
+    fn from(p: StringPredicateProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringPredicateProperties> for String {
+    fn from(p: StringPredicateProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -0,0 +1,60 @@
+use lazy_static::lazy_static;
+use regex::Regex;
+use serde_json::{json, Map, Value};
+use std::collections::HashMap;
+
+/// Applies the compiled regular expression on lhs. The replacement is only used by regex_replace_all. The fourth
+/// argument is the maximum length of a generated string in bytes.
+pub type StringRegexFunction = fn(&Regex, String, String, usize) -> Result<Value, String>;
+
+pub const FN_REGEX_IS_MATCH: StringRegexFunction = |regex, lhs, _, _| Ok(json!(regex.is_match(lhs.as_str())));
+/// Replaces all matches like `Regex::replace_all`, but refuses results which would exceed the maximum length instead
+/// of allocating them. Before a match is expanded, the expansion is estimated by assuming that every `$` of the
+/// replacement refers to the whole match.
+pub const FN_REGEX_REPLACE_ALL: StringRegexFunction = |regex, lhs, replacement, max_result_length| {
+    let references = replacement.matches('$').count();
+    let mut result = String::new();
+    let mut last_match = 0;
+    for captures in regex.captures_iter(lhs.as_str()) {
+        let m = captures.get(0).unwrap();
+        let expansion = references.saturating_mul(m.len()).saturating_add(replacement.len());
+        if result.len().saturating_add(m.start() - last_match).saturating_add(expansion) > max_result_length {
+            return Err(too_long(max_result_length));
+        }
+        result.push_str(&lhs[last_match..m.start()]);
+        captures.expand(replacement.as_str(), &mut result);
+        last_match = m.end();
+    }
+    if result.len() + (lhs.len() - last_match) > max_result_length {
+        return Err(too_long(max_result_length));
+    }
+    result.push_str(&lhs[last_match..]);
+    Ok(json!(result))
+};
+pub const FN_REGEX_FIND_ALL: StringRegexFunction = |regex, lhs, _, _| Ok(json!(regex.find_iter(lhs.as_str()).map(|m| m.as_str()).collect::<Vec<&str>>()));
+/// Returns the named capture groups of the first match as object. Groups which didn't participate in the match are null.
+pub const FN_REGEX_CAPTURES: StringRegexFunction = |regex, lhs, _, _| {
+    let mut groups = Map::new();
+    if let Some(captures) = regex.captures(lhs.as_str()) {
+        for name in regex.capture_names().flatten() {
+            let value = captures.name(name).map(|m| json!(m.as_str())).unwrap_or(Value::Null);
+            groups.insert(String::from(name), value);
+        }
+    }
+    Ok(Value::Object(groups))
+};
+
+lazy_static! {
+    pub static ref STRING_REGEXES: HashMap<&'static str, StringRegexFunction> = vec![
+        ("regex_is_match", FN_REGEX_IS_MATCH),
+        ("regex_replace_all", FN_REGEX_REPLACE_ALL),
+        ("regex_find_all", FN_REGEX_FIND_ALL),
+        ("regex_captures", FN_REGEX_CAPTURES),
+    ]
+    .into_iter()
+    .collect();
+}
+
+fn too_long(max_result_length: usize) -> String {
+    format!("The replaced string exceeds the maximum result length of {} bytes", max_result_length)
+}
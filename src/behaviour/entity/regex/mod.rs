@@ -0,0 +1,8 @@
+pub use function::StringRegexFunction;
+pub use function::STRING_REGEXES;
+pub use string_regex::StringRegex;
+pub use string_regex_properties::StringRegexProperties;
+
+pub mod function;
+pub mod string_regex;
+pub mod string_regex_properties;
@@ -0,0 +1,211 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::regex::string_regex_properties::StringRegexProperties;
+use crate::behaviour::entity::regex::StringRegexFunction;
+use crate::config::get_config;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+#[derive(Debug, Copy, Clone)]
+pub enum StringRegexPosition {
+    LHS,
+    PATTERN,
+    REPLACEMENT,
+}
+
+pub type StringRegexExpressionValue = (StringRegexPosition, Value);
+
+/// The state of the inputs of a string regex.
+///
+/// The compiled regular expression is part of the state and is only recompiled if the pattern changes. If the pattern
+/// is invalid, the state contains the error message instead.
+#[derive(Debug, Clone)]
+pub struct StringRegexExpression {
+    pub lhs: String,
+    pub pattern: String,
+    pub replacement: String,
+    pub regex: Result<Regex, String>,
+}
+
+impl StringRegexExpression {
+    /// Initializes the expression with the current values of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance) -> Self {
+        let pattern = e
+            .get(StringRegexProperties::PATTERN.as_ref())
+            .and_then(|v| v.as_str().map(String::from))
+            .unwrap_or_default();
+        StringRegexExpression {
+            lhs: e
+                .get(StringRegexProperties::LHS.as_ref())
+                .and_then(|v| v.as_str().map(String::from))
+                .unwrap_or_default(),
+            regex: compile(pattern.as_str()),
+            pattern,
+            replacement: e
+                .get(StringRegexProperties::REPLACEMENT.as_ref())
+                .and_then(|v| v.as_str().map(String::from))
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn set(self, position: StringRegexPosition, value: &Value) -> Self {
+        match position {
+            StringRegexPosition::LHS => StringRegexExpression {
+                lhs: value.as_str().map(String::from).unwrap_or_default(),
+                ..self
+            },
+            StringRegexPosition::PATTERN => {
+                let pattern = value.as_str().map(String::from).unwrap_or_default();
+                if pattern == self.pattern {
+                    return self;
+                }
+                StringRegexExpression {
+                    regex: compile(pattern.as_str()),
+                    pattern,
+                    ..self
+                }
+            }
+            StringRegexPosition::REPLACEMENT => StringRegexExpression {
+                replacement: value.as_str().map(String::from).unwrap_or_default(),
+                ..self
+            },
+        }
+    }
+}
+
+fn compile(pattern: &str) -> Result<Regex, String> {
+    debug!("Compiling regular expression {}", pattern);
+    Regex::new(pattern).map_err(|e| e.to_string())
+}
+
+/// Generic implementation of regular expressions with a string input (LHS), the pattern and a result. The data type of
+/// the result depends on the function. An invalid pattern and replacements which would exceed the maximum result
+/// length are reported on the error output.
+///
+/// Entity types which replace matches additionally provide the input REPLACEMENT.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringRegex<'a> {
+    pub lhs: RwLock<Stream<'a, StringRegexExpressionValue>>,
+
+    pub pattern: RwLock<Stream<'a, StringRegexExpressionValue>>,
+
+    pub replacement: Option<RwLock<Stream<'a, StringRegexExpressionValue>>>,
+
+    pub f: StringRegexFunction,
+
+    pub internal_result: RwLock<Stream<'a, Result<Value, String>>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringRegex<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringRegexFunction) -> StringRegex<'static> {
+        let lhs = e
+            .properties
+            .get(StringRegexProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringRegexExpressionValue { (StringRegexPosition::LHS, v.clone()) });
+        let pattern = e
+            .properties
+            .get(StringRegexProperties::PATTERN.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringRegexExpressionValue { (StringRegexPosition::PATTERN, v.clone()) });
+        let replacement = e.properties.get(StringRegexProperties::REPLACEMENT.as_ref()).map(|property| {
+            property
+                .stream
+                .read()
+                .unwrap()
+                .map(|v| -> StringRegexExpressionValue { (StringRegexPosition::REPLACEMENT, v.clone()) })
+        });
+
+        let mut inputs = lhs.merge(&pattern);
+        if let Some(replacement) = &replacement {
+            inputs = inputs.merge(replacement);
+        }
+        let expression = inputs.fold(StringRegexExpression::new(&e), |old_state, (o, value)| old_state.set(*o, value));
+
+        // The internal result
+        let max_result_length = get_config().max_result_length;
+        let internal_result = expression.map(move |e| match &e.regex {
+            Ok(regex) => f(regex, e.lhs.clone(), e.replacement.clone(), max_result_length),
+            Err(error) => Err(error.clone()),
+        });
+
+        let handle_id = e.properties.get(StringRegexProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_regex = StringRegex {
+            lhs: RwLock::new(lhs),
+            pattern: RwLock::new(pattern),
+            replacement: replacement.map(RwLock::new),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_regex.internal_result.read().unwrap().observe_with_handle(
+            move |v| match v {
+                Ok(result) => {
+                    debug!("Setting result of string regex: {}", result);
+                    e.set(StringRegexProperties::ERROR.to_string(), json!(""));
+                    e.set(StringRegexProperties::RESULT.to_string(), result.clone());
+                }
+                Err(error) => {
+                    debug!("Setting error of string regex: {}", error);
+                    e.set(StringRegexProperties::ERROR.to_string(), json!(error));
+                }
+            },
+            handle_id,
+        );
+
+        string_regex
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringRegex<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string regex {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringRegex<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringRegexProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringRegexProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringRegex<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string regex");
+        self.disconnect();
+    }
+}
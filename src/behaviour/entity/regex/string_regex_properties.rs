@@ -0,0 +1,57 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringRegexProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "pattern")]
+    PATTERN,
+    /// Only available on entity types which replace matches (regex_replace_all)
+    #[strum(serialize = "replacement")]
+    REPLACEMENT,
+    /// The data type of the result depends on the entity type
+    #[strum(serialize = "result")]
+    RESULT,
+    #[strum(serialize = "error")]
+    ERROR,
+}
+
+impl StringRegexProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringRegexProperties::LHS => json!(""),
+            StringRegexProperties::PATTERN => json!(""),
+            StringRegexProperties::REPLACEMENT => json!(""),
+            StringRegexProperties::RESULT => Value::Null,
+            StringRegexProperties::ERROR => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringRegexProperties::LHS),
+            NamedProperty::from(StringRegexProperties::PATTERN),
+            NamedProperty::from(StringRegexProperties::RESULT),
+            NamedProperty::from(StringRegexProperties::ERROR),
+        ]
+    }
+}
+
+impl From<StringRegexProperties> for NamedProperty {
+    fn from(p: StringRegexProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringRegexProperties> for String {
+    fn from(p: StringRegexProperties) -> Self {
+        p.to_string()
+    }
+}
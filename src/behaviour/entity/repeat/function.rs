@@ -0,0 +1,21 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+
+/// Repeats lhs count times. The third argument is the maximum length of the result in bytes.
+pub type StringRepeatFunction = fn(String, usize, usize) -> Result<String, String>;
+
+/// Refuses to allocate results longer than the maximum length instead of truncating them silently.
+pub const FN_REPEAT: StringRepeatFunction = |lhs, count, max_result_length| match lhs.len().checked_mul(count) {
+    Some(length) if length <= max_result_length => Ok(lhs.repeat(count)),
+    _ => Err(format!(
+        "Repeating {} bytes {} times exceeds the maximum result length of {} bytes",
+        lhs.len(),
+        count,
+        max_result_length
+    )),
+};
+
+lazy_static! {
+    pub static ref STRING_REPEATS: HashMap<&'static str, StringRepeatFunction> = vec![("repeat", FN_REPEAT)].into_iter().collect();
+}
@@ -0,0 +1,8 @@
+pub use function::StringRepeatFunction;
+pub use function::STRING_REPEATS;
+pub use string_repeat::StringRepeat;
+pub use string_repeat_properties::StringRepeatProperties;
+
+pub mod function;
+pub mod string_repeat;
+pub mod string_repeat_properties;
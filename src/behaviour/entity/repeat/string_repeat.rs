@@ -0,0 +1,137 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::repeat::string_repeat_properties::StringRepeatProperties;
+use crate::behaviour::entity::repeat::StringRepeatFunction;
+use crate::behaviour::entity::value::to_index;
+use crate::config::get_config;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::expression::{Expression, ExpressionValue, OperatorPosition};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+pub type StringRepeatExpressionValue = ExpressionValue<Value>;
+
+/// Generic implementation of string repetitions with two inputs (LHS, COUNT) and one result. Results which would exceed
+/// the configured maximum result length are refused and reported on the error output.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringRepeat<'a> {
+    pub lhs: RwLock<Stream<'a, StringRepeatExpressionValue>>,
+
+    pub count: RwLock<Stream<'a, StringRepeatExpressionValue>>,
+
+    pub f: StringRepeatFunction,
+
+    pub internal_result: RwLock<Stream<'a, Result<String, String>>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringRepeat<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringRepeatFunction) -> StringRepeat<'static> {
+        let lhs = e
+            .properties
+            .get(StringRepeatProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringRepeatExpressionValue { (OperatorPosition::LHS, v.clone()) });
+        let count = e
+            .properties
+            .get(StringRepeatProperties::COUNT.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringRepeatExpressionValue { (OperatorPosition::RHS, v.clone()) });
+
+        let expression = lhs.merge(&count).fold(
+            Expression::new(
+                e.get(StringRepeatProperties::LHS.as_ref())
+                    .unwrap_or_else(|| StringRepeatProperties::LHS.default_value()),
+                e.get(StringRepeatProperties::COUNT.as_ref())
+                    .unwrap_or_else(|| StringRepeatProperties::COUNT.default_value()),
+            ),
+            |old_state, (o, value)| match *o {
+                OperatorPosition::LHS => old_state.lhs(value.clone()),
+                OperatorPosition::RHS => old_state.rhs(value.clone()),
+            },
+        );
+
+        // The internal result
+        let max_result_length = get_config().max_result_length;
+        let internal_result = expression.map(move |e| match (e.lhs.as_str(), to_index(&e.rhs)) {
+            (Some(lhs), Some(count)) if count >= 0 => f(String::from(lhs), count as usize, max_result_length),
+            (Some(_), _) => Err(String::from("The count must be a number greater than or equal to zero")),
+            (None, _) => Err(String::from("The lhs is not a string")),
+        });
+
+        let handle_id = e.properties.get(StringRepeatProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_repeat = StringRepeat {
+            lhs: RwLock::new(lhs),
+            count: RwLock::new(count),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_repeat.internal_result.read().unwrap().observe_with_handle(
+            move |v| match v {
+                Ok(result) => {
+                    debug!("Setting result of string repeat");
+                    e.set(StringRepeatProperties::ERROR.to_string(), json!(""));
+                    e.set(StringRepeatProperties::RESULT.to_string(), json!(result));
+                }
+                Err(error) => {
+                    debug!("Setting error of string repeat: {}", error);
+                    e.set(StringRepeatProperties::ERROR.to_string(), json!(error));
+                }
+            },
+            handle_id,
+        );
+
+        string_repeat
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringRepeat<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string repeat {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringRepeat<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringRepeatProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringRepeatProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringRepeat<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string repeat");
+        self.disconnect();
+    }
+}
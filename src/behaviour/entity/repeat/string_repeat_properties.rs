@@ -0,0 +1,52 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringRepeatProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "count")]
+    COUNT,
+    #[strum(serialize = "result")]
+    RESULT,
+    #[strum(serialize = "error")]
+    ERROR,
+}
+
+impl StringRepeatProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringRepeatProperties::LHS => json!(""),
+            StringRepeatProperties::COUNT => json!(1),
+            StringRepeatProperties::RESULT => json!(""),
+            StringRepeatProperties::ERROR => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringRepeatProperties::LHS),
+            NamedProperty::from(StringRepeatProperties::COUNT),
+            NamedProperty::from(StringRepeatProperties::RESULT),
+            NamedProperty::from(StringRepeatProperties::ERROR),
+        ]
+    }
+}
+
+impl From<StringRepeatProperties> for NamedProperty {
+    fn from(p: StringRepeatProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringRepeatProperties> for String {
+    fn from(p: StringRepeatProperties) -> Self {
+        p.to_string()
+    }
+}
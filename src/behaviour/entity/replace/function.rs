@@ -0,0 +1,57 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+/// Replaces occurrences of search in lhs with replace. The count is only used by replacen. The fifth argument is the
+/// maximum length of the result in bytes.
+///
+/// An empty search string leaves lhs unchanged.
+pub type StringReplaceFunction = fn(String, String, String, usize, usize) -> Result<String, String>;
+
+pub const FN_REPLACE: StringReplaceFunction = |lhs, search, replace, _, max_result_length| {
+    if search.is_empty() {
+        return Ok(lhs);
+    }
+    check_length(lhs.as_str(), search.as_str(), replace.as_str(), usize::MAX, max_result_length)?;
+    Ok(lhs.replace(search.as_str(), replace.as_str()))
+};
+pub const FN_REPLACEN: StringReplaceFunction = |lhs, search, replace, count, max_result_length| {
+    if search.is_empty() {
+        return Ok(lhs);
+    }
+    check_length(lhs.as_str(), search.as_str(), replace.as_str(), count, max_result_length)?;
+    Ok(lhs.replacen(search.as_str(), replace.as_str(), count))
+};
+pub const FN_REPLACE_FIRST: StringReplaceFunction = |lhs, search, replace, _, max_result_length| {
+    if search.is_empty() {
+        return Ok(lhs);
+    }
+    check_length(lhs.as_str(), search.as_str(), replace.as_str(), 1, max_result_length)?;
+    Ok(lhs.replacen(search.as_str(), replace.as_str(), 1))
+};
+
+lazy_static! {
+    pub static ref STRING_REPLACES: HashMap<&'static str, StringReplaceFunction> =
+        vec![("replace", FN_REPLACE), ("replacen", FN_REPLACEN), ("replace_first", FN_REPLACE_FIRST),]
+            .into_iter()
+            .collect();
+}
+
+/// Refuses replacements which would exceed the maximum result length instead of allocating them.
+fn check_length(lhs: &str, search: &str, replace: &str, count: usize, max_result_length: usize) -> Result<(), String> {
+    if replace.len() <= search.len() {
+        return Ok(());
+    }
+    let replacements = lhs.matches(search).take(count).count();
+    match (replace.len() - search.len())
+        .checked_mul(replacements)
+        .and_then(|length| length.checked_add(lhs.len()))
+    {
+        Some(length) if length <= max_result_length => Ok(()),
+        _ => Err(format!(
+            "Replacing {} occurrences in {} bytes exceeds the maximum result length of {} bytes",
+            replacements,
+            lhs.len(),
+            max_result_length
+        )),
+    }
+}
@@ -0,0 +1,8 @@
+pub use function::StringReplaceFunction;
+pub use function::STRING_REPLACES;
+pub use string_replace::StringReplace;
+pub use string_replace_properties::StringReplaceProperties;
+
+pub mod function;
+pub mod string_replace;
+pub mod string_replace_properties;
@@ -0,0 +1,214 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::replace::string_replace_properties::StringReplaceProperties;
+use crate::behaviour::entity::replace::StringReplaceFunction;
+use crate::behaviour::entity::value::to_index;
+use crate::config::get_config;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+#[derive(Debug, Copy, Clone)]
+pub enum StringReplacePosition {
+    LHS,
+    SEARCH,
+    REPLACE,
+    COUNT,
+}
+
+pub type StringReplaceExpressionValue = (StringReplacePosition, Value);
+
+/// The state of the three (or four) inputs of a string replace.
+#[derive(Debug, Clone)]
+pub struct StringReplaceExpression {
+    pub lhs: String,
+    pub search: String,
+    pub replace: String,
+    pub count: usize,
+}
+
+impl StringReplaceExpression {
+    /// Initializes the expression with the current values of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance) -> Self {
+        StringReplaceExpression {
+            lhs: to_string(e.get(StringReplaceProperties::LHS.as_ref()), StringReplaceProperties::LHS),
+            search: to_string(e.get(StringReplaceProperties::SEARCH.as_ref()), StringReplaceProperties::SEARCH),
+            replace: to_string(e.get(StringReplaceProperties::REPLACE.as_ref()), StringReplaceProperties::REPLACE),
+            count: to_count(e.get(StringReplaceProperties::COUNT.as_ref())),
+        }
+    }
+
+    pub fn set(self, position: StringReplacePosition, value: &Value) -> Self {
+        match position {
+            StringReplacePosition::LHS => StringReplaceExpression {
+                lhs: to_string(Some(value.clone()), StringReplaceProperties::LHS),
+                ..self
+            },
+            StringReplacePosition::SEARCH => StringReplaceExpression {
+                search: to_string(Some(value.clone()), StringReplaceProperties::SEARCH),
+                ..self
+            },
+            StringReplacePosition::REPLACE => StringReplaceExpression {
+                replace: to_string(Some(value.clone()), StringReplaceProperties::REPLACE),
+                ..self
+            },
+            StringReplacePosition::COUNT => StringReplaceExpression {
+                count: to_count(Some(value.clone())),
+                ..self
+            },
+        }
+    }
+}
+
+fn to_string(value: Option<Value>, property: StringReplaceProperties) -> String {
+    match value.as_ref().and_then(|v| v.as_str()) {
+        Some(s) => String::from(s),
+        None => String::from(property.default_value().as_str().unwrap()),
+    }
+}
+
+/// Floating point counts are truncated and negative counts are treated as zero.
+fn to_count(value: Option<Value>) -> usize {
+    value
+        .and_then(|v| to_index(&v))
+        .or_else(|| to_index(&StringReplaceProperties::COUNT.default_value()))
+        .unwrap_or_default()
+        .max(0) as usize
+}
+
+/// Generic implementation of string replace operations with three inputs (LHS, SEARCH, REPLACE) and one result.
+///
+/// Entity types which limit the number of replacements additionally provide the input COUNT. Replacements which would
+/// exceed the maximum result length are reported on the error output.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringReplace<'a> {
+    pub lhs: RwLock<Stream<'a, StringReplaceExpressionValue>>,
+
+    pub search: RwLock<Stream<'a, StringReplaceExpressionValue>>,
+
+    pub replace: RwLock<Stream<'a, StringReplaceExpressionValue>>,
+
+    pub count: Option<RwLock<Stream<'a, StringReplaceExpressionValue>>>,
+
+    pub f: StringReplaceFunction,
+
+    pub internal_result: RwLock<Stream<'a, Result<String, String>>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringReplace<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringReplaceFunction) -> StringReplace<'static> {
+        let lhs = e
+            .properties
+            .get(StringReplaceProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringReplaceExpressionValue { (StringReplacePosition::LHS, v.clone()) });
+        let search = e
+            .properties
+            .get(StringReplaceProperties::SEARCH.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringReplaceExpressionValue { (StringReplacePosition::SEARCH, v.clone()) });
+        let replace = e
+            .properties
+            .get(StringReplaceProperties::REPLACE.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringReplaceExpressionValue { (StringReplacePosition::REPLACE, v.clone()) });
+        let count = e.properties.get(StringReplaceProperties::COUNT.as_ref()).map(|property| {
+            property
+                .stream
+                .read()
+                .unwrap()
+                .map(|v| -> StringReplaceExpressionValue { (StringReplacePosition::COUNT, v.clone()) })
+        });
+
+        let mut inputs = lhs.merge(&search).merge(&replace);
+        if let Some(count) = &count {
+            inputs = inputs.merge(count);
+        }
+        let expression = inputs.fold(StringReplaceExpression::new(&e), |old_state, (o, value)| old_state.set(*o, value));
+
+        // The internal result
+        let max_result_length = get_config().max_result_length;
+        let internal_result = expression.map(move |e| f(e.lhs.clone(), e.search.clone(), e.replace.clone(), e.count, max_result_length));
+
+        let handle_id = e.properties.get(StringReplaceProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_replace = StringReplace {
+            lhs: RwLock::new(lhs),
+            search: RwLock::new(search),
+            replace: RwLock::new(replace),
+            count: count.map(RwLock::new),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_replace.internal_result.read().unwrap().observe_with_handle(
+            move |v| match v {
+                Ok(result) => {
+                    debug!("Setting result of string replace: {}", result);
+                    e.set(StringReplaceProperties::ERROR.to_string(), json!(""));
+                    e.set(StringReplaceProperties::RESULT.to_string(), json!(result));
+                }
+                Err(error) => {
+                    debug!("Setting error of string replace: {}", error);
+                    e.set(StringReplaceProperties::ERROR.to_string(), json!(error));
+                }
+            },
+            handle_id,
+        );
+
+        string_replace
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringReplace<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string replace {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringReplace<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringReplaceProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringReplaceProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringReplace<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string replace");
+        self.disconnect();
+    }
+}
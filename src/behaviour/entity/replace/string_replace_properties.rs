@@ -0,0 +1,60 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringReplaceProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "search")]
+    SEARCH,
+    #[strum(serialize = "replace")]
+    REPLACE,
+    /// Only available on entity types which limit the number of replacements (replacen)
+    #[strum(serialize = "count")]
+    COUNT,
+    #[strum(serialize = "result")]
+    RESULT,
+    #[strum(serialize = "error")]
+    ERROR,
+}
+
+impl StringReplaceProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringReplaceProperties::LHS => json!(""),
+            StringReplaceProperties::SEARCH => json!(""),
+            StringReplaceProperties::REPLACE => json!(""),
+            StringReplaceProperties::COUNT => json!(1),
+            StringReplaceProperties::RESULT => json!(""),
+            StringReplaceProperties::ERROR => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringReplaceProperties::LHS),
+            NamedProperty::from(StringReplaceProperties::SEARCH),
+            NamedProperty::from(StringReplaceProperties::REPLACE),
+            NamedProperty::from(StringReplaceProperties::RESULT),
+            NamedProperty::from(StringReplaceProperties::ERROR),
+        ]
+    }
+}
+
+impl From<StringReplaceProperties> for NamedProperty {
+    fn from(p: StringReplaceProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringReplaceProperties> for String {
+    fn from(p: StringReplaceProperties) -> Self {
+        p.to_string()
+    }
+}
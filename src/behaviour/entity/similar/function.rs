@@ -0,0 +1,23 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+
+use crate::behaviour::entity::similarity::NORMALIZED_SIMILARITIES;
+
+/// Decides whether two strings are similar. The third argument is the name of the metric and the fourth argument is
+/// the threshold.
+pub type StringSimilarFunction = fn(String, String, String, f64) -> Result<bool, String>;
+
+/// True if the normalized similarity of lhs and rhs is at least the threshold. Unknown metrics are rejected.
+pub const FN_SIMILAR: StringSimilarFunction = |lhs, rhs, metric, threshold| match NORMALIZED_SIMILARITIES.get(metric.as_str()) {
+    Some(similarity) => Ok(similarity(lhs, rhs) >= threshold),
+    None => {
+        let mut metrics: Vec<&str> = NORMALIZED_SIMILARITIES.keys().copied().collect();
+        metrics.sort_unstable();
+        Err(format!("Unknown metric {} (expected one of {})", metric, metrics.join(", ")))
+    }
+};
+
+lazy_static! {
+    pub static ref STRING_SIMILARS: HashMap<&'static str, StringSimilarFunction> = vec![("similar", FN_SIMILAR)].into_iter().collect();
+}
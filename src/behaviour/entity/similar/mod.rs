@@ -0,0 +1,8 @@
+pub use function::StringSimilarFunction;
+pub use function::STRING_SIMILARS;
+pub use string_similar::StringSimilar;
+pub use string_similar_properties::StringSimilarProperties;
+
+pub mod function;
+pub mod string_similar;
+pub mod string_similar_properties;
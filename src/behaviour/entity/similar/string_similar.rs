@@ -0,0 +1,207 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::similar::string_similar_properties::StringSimilarProperties;
+use crate::behaviour::entity::similar::StringSimilarFunction;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::gate::Gate;
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+#[derive(Debug, Copy, Clone)]
+pub enum StringSimilarPosition {
+    LHS,
+    RHS,
+    METRIC,
+    THRESHOLD,
+}
+
+pub type StringSimilarExpressionValue = (StringSimilarPosition, Value);
+
+/// The state of the inputs of a string similar.
+#[derive(Debug, Clone)]
+pub struct StringSimilarExpression {
+    pub lhs: String,
+    pub rhs: String,
+    pub metric: String,
+    pub threshold: f64,
+}
+
+impl StringSimilarExpression {
+    /// Initializes the expression with the current values of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance) -> Self {
+        let get = |property: StringSimilarProperties| e.get(property.as_ref()).unwrap_or_else(|| property.default_value());
+        StringSimilarExpression {
+            lhs: to_string(&get(StringSimilarProperties::LHS)),
+            rhs: to_string(&get(StringSimilarProperties::RHS)),
+            metric: to_string(&get(StringSimilarProperties::METRIC)),
+            threshold: to_threshold(&get(StringSimilarProperties::THRESHOLD)),
+        }
+    }
+
+    pub fn set(self, position: StringSimilarPosition, value: &Value) -> Self {
+        match position {
+            StringSimilarPosition::LHS => StringSimilarExpression { lhs: to_string(value), ..self },
+            StringSimilarPosition::RHS => StringSimilarExpression { rhs: to_string(value), ..self },
+            StringSimilarPosition::METRIC => StringSimilarExpression {
+                metric: to_string(value),
+                ..self
+            },
+            StringSimilarPosition::THRESHOLD => StringSimilarExpression {
+                threshold: to_threshold(value),
+                ..self
+            },
+        }
+    }
+}
+
+fn to_string(value: &Value) -> String {
+    value.as_str().map(String::from).unwrap_or_default()
+}
+
+fn to_threshold(value: &Value) -> f64 {
+    value
+        .as_f64()
+        .or_else(|| StringSimilarProperties::THRESHOLD.default_value().as_f64())
+        .unwrap_or_default()
+}
+
+/// Generic implementation of fuzzy string matching with two inputs (LHS, RHS), the metric, the threshold and one
+/// result. Unknown metrics are reported on the error output.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringSimilar<'a> {
+    pub lhs: RwLock<Stream<'a, StringSimilarExpressionValue>>,
+
+    pub rhs: RwLock<Stream<'a, StringSimilarExpressionValue>>,
+
+    pub metric: RwLock<Stream<'a, StringSimilarExpressionValue>>,
+
+    pub threshold: RwLock<Stream<'a, StringSimilarExpressionValue>>,
+
+    pub f: StringSimilarFunction,
+
+    pub internal_result: RwLock<Stream<'a, Result<bool, String>>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringSimilar<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringSimilarFunction) -> StringSimilar<'static> {
+        let lhs = e
+            .properties
+            .get(StringSimilarProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringSimilarExpressionValue { (StringSimilarPosition::LHS, v.clone()) });
+        let rhs = e
+            .properties
+            .get(StringSimilarProperties::RHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringSimilarExpressionValue { (StringSimilarPosition::RHS, v.clone()) });
+        let metric = e
+            .properties
+            .get(StringSimilarProperties::METRIC.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringSimilarExpressionValue { (StringSimilarPosition::METRIC, v.clone()) });
+        let threshold = e
+            .properties
+            .get(StringSimilarProperties::THRESHOLD.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringSimilarExpressionValue { (StringSimilarPosition::THRESHOLD, v.clone()) });
+
+        let expression = lhs
+            .merge(&rhs)
+            .merge(&metric)
+            .merge(&threshold)
+            .fold(StringSimilarExpression::new(&e), |old_state, (o, value)| old_state.set(*o, value));
+
+        // The internal result
+        let internal_result = expression.map(move |e| f(e.lhs.clone(), e.rhs.clone(), e.metric.clone(), e.threshold));
+
+        let handle_id = e.properties.get(StringSimilarProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_similar = StringSimilar {
+            lhs: RwLock::new(lhs),
+            rhs: RwLock::new(rhs),
+            metric: RwLock::new(metric),
+            threshold: RwLock::new(threshold),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_similar.internal_result.read().unwrap().observe_with_handle(
+            move |v| match v {
+                Ok(result) => {
+                    debug!("Setting result of string similar: {}", result);
+                    e.set(StringSimilarProperties::ERROR.to_string(), json!(""));
+                    e.set(StringSimilarProperties::RESULT.to_string(), json!(*result));
+                }
+                Err(error) => {
+                    debug!("Setting error of string similar: {}", error);
+                    e.set(StringSimilarProperties::ERROR.to_string(), json!(error));
+                }
+            },
+            handle_id,
+        );
+
+        string_similar
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringSimilar<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string similar {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringSimilar<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringSimilarProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringSimilarProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+impl Gate for StringSimilar<'_> {
+    fn rhs(&self, value: Value) {
+        self.entity.set(StringSimilarProperties::RHS.as_ref(), value);
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringSimilar<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string similar");
+        self.disconnect();
+    }
+}
@@ -0,0 +1,62 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringSimilarProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "rhs")]
+    RHS,
+    /// The name of the metric: levenshtein, damerau_levenshtein, jaro_winkler or sorensen_dice
+    #[strum(serialize = "metric")]
+    METRIC,
+    /// The minimum normalized similarity between 0 and 1
+    #[strum(serialize = "threshold")]
+    THRESHOLD,
+    #[strum(serialize = "result")]
+    RESULT,
+    #[strum(serialize = "error")]
+    ERROR,
+}
+
+impl StringSimilarProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringSimilarProperties::LHS => json!(""),
+            StringSimilarProperties::RHS => json!(""),
+            StringSimilarProperties::METRIC => json!("jaro_winkler"),
+            StringSimilarProperties::THRESHOLD => json!(0.8),
+            StringSimilarProperties::RESULT => json!(false),
+            StringSimilarProperties::ERROR => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringSimilarProperties::LHS),
+            NamedProperty::from(StringSimilarProperties::RHS),
+            NamedProperty::from(StringSimilarProperties::METRIC),
+            NamedProperty::from(StringSimilarProperties::THRESHOLD),
+            NamedProperty::from(StringSimilarProperties::RESULT),
+            NamedProperty::from(StringSimilarProperties::ERROR),
+        ]
+    }
+}
+
+impl From<StringSimilarProperties> for NamedProperty {
+    fn from(p: StringSimilarProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringSimilarProperties> for String {
+    fn from(p: StringSimilarProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -0,0 +1,39 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+
+/// Calculates the distance or the similarity of two strings.
+pub type StringSimilarityFunction = fn(String, String) -> f64;
+
+/// The minimum number of insertions, deletions and substitutions of chars.
+pub const FN_LEVENSHTEIN: StringSimilarityFunction = |lhs, rhs| strsim::levenshtein(lhs.as_str(), rhs.as_str()) as f64;
+/// Like levenshtein, but transpositions of adjacent chars count as a single edit.
+pub const FN_DAMERAU_LEVENSHTEIN: StringSimilarityFunction = |lhs, rhs| strsim::damerau_levenshtein(lhs.as_str(), rhs.as_str()) as f64;
+/// The similarity between 0 and 1 (identical). Strings with a common prefix are considered more similar.
+pub const FN_JARO_WINKLER: StringSimilarityFunction = |lhs, rhs| strsim::jaro_winkler(lhs.as_str(), rhs.as_str());
+/// The similarity between 0 and 1 (identical) based on the common bigrams.
+pub const FN_SORENSEN_DICE: StringSimilarityFunction = |lhs, rhs| strsim::sorensen_dice(lhs.as_str(), rhs.as_str());
+/// Levenshtein normalized to a similarity between 0 and 1 (identical).
+pub const FN_NORMALIZED_LEVENSHTEIN: StringSimilarityFunction = |lhs, rhs| strsim::normalized_levenshtein(lhs.as_str(), rhs.as_str());
+/// Damerau-Levenshtein normalized to a similarity between 0 and 1 (identical).
+pub const FN_NORMALIZED_DAMERAU_LEVENSHTEIN: StringSimilarityFunction = |lhs, rhs| strsim::normalized_damerau_levenshtein(lhs.as_str(), rhs.as_str());
+
+lazy_static! {
+    pub static ref STRING_SIMILARITIES: HashMap<&'static str, StringSimilarityFunction> = vec![
+        ("levenshtein", FN_LEVENSHTEIN),
+        ("damerau_levenshtein", FN_DAMERAU_LEVENSHTEIN),
+        ("jaro_winkler", FN_JARO_WINKLER),
+        ("sorensen_dice", FN_SORENSEN_DICE),
+    ]
+    .into_iter()
+    .collect();
+    /// The metrics normalized to a similarity between 0 and 1 (identical), so that they can be compared with a threshold.
+    pub static ref NORMALIZED_SIMILARITIES: HashMap<&'static str, StringSimilarityFunction> = vec![
+        ("levenshtein", FN_NORMALIZED_LEVENSHTEIN),
+        ("damerau_levenshtein", FN_NORMALIZED_DAMERAU_LEVENSHTEIN),
+        ("jaro_winkler", FN_JARO_WINKLER),
+        ("sorensen_dice", FN_SORENSEN_DICE),
+    ]
+    .into_iter()
+    .collect();
+}
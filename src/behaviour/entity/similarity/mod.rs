@@ -0,0 +1,9 @@
+pub use function::StringSimilarityFunction;
+pub use function::NORMALIZED_SIMILARITIES;
+pub use function::STRING_SIMILARITIES;
+pub use string_similarity::StringSimilarity;
+pub use string_similarity_properties::StringSimilarityProperties;
+
+pub mod function;
+pub mod string_similarity;
+pub mod string_similarity_properties;
@@ -0,0 +1,131 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::similarity::string_similarity_properties::StringSimilarityProperties;
+use crate::behaviour::entity::similarity::StringSimilarityFunction;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::expression::{Expression, ExpressionValue, OperatorPosition};
+use crate::reactive::entity::gate::Gate;
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+pub type StringSimilarityExpressionValue = ExpressionValue<String>;
+
+/// Generic implementation of string similarity metrics with two inputs (LHS,RHS) and one numeric result.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringSimilarity<'a> {
+    pub lhs: RwLock<Stream<'a, StringSimilarityExpressionValue>>,
+
+    pub rhs: RwLock<Stream<'a, StringSimilarityExpressionValue>>,
+
+    pub f: StringSimilarityFunction,
+
+    pub internal_result: RwLock<Stream<'a, f64>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringSimilarity<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringSimilarityFunction) -> StringSimilarity<'static> {
+        let lhs = e
+            .properties
+            .get(StringSimilarityProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringSimilarityExpressionValue { (OperatorPosition::LHS, v.as_str().map(String::from).unwrap_or_default()) });
+        let rhs = e
+            .properties
+            .get(StringSimilarityProperties::RHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringSimilarityExpressionValue { (OperatorPosition::RHS, v.as_str().map(String::from).unwrap_or_default()) });
+
+        let expression = lhs.merge(&rhs).fold(
+            Expression::new(
+                e.get(StringSimilarityProperties::LHS.as_ref())
+                    .and_then(|v| v.as_str().map(String::from))
+                    .unwrap_or_default(),
+                e.get(StringSimilarityProperties::RHS.as_ref())
+                    .and_then(|v| v.as_str().map(String::from))
+                    .unwrap_or_default(),
+            ),
+            |old_state, (o, value)| match *o {
+                OperatorPosition::LHS => old_state.lhs(value.clone()),
+                OperatorPosition::RHS => old_state.rhs(value.clone()),
+            },
+        );
+
+        // The internal result
+        let internal_result = expression.map(move |e| f(e.lhs.clone(), e.rhs.clone()));
+
+        let handle_id = e.properties.get(StringSimilarityProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_similarity = StringSimilarity {
+            lhs: RwLock::new(lhs),
+            rhs: RwLock::new(rhs),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_similarity.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string similarity: {}", v);
+                e.set(StringSimilarityProperties::RESULT.to_string(), json!(*v));
+            },
+            handle_id,
+        );
+
+        string_similarity
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringSimilarity<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string similarity {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringSimilarity<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringSimilarityProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringSimilarityProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+impl Gate for StringSimilarity<'_> {
+    fn rhs(&self, value: Value) {
+        self.entity.set(StringSimilarityProperties::RHS.as_ref(), value);
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringSimilarity<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string similarity");
+        self.disconnect();
+    }
+}
@@ -0,0 +1,48 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringSimilarityProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "rhs")]
+    RHS,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringSimilarityProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringSimilarityProperties::LHS => json!(""),
+            StringSimilarityProperties::RHS => json!(""),
+            StringSimilarityProperties::RESULT => json!(0),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringSimilarityProperties::LHS),
+            NamedProperty::from(StringSimilarityProperties::RHS),
+            NamedProperty::from(StringSimilarityProperties::RESULT),
+        ]
+    }
+}
+
+impl From<StringSimilarityProperties> for NamedProperty {
+    fn from(p: StringSimilarityProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringSimilarityProperties> for String {
+    fn from(p: StringSimilarityProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -0,0 +1,45 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+/// Operates on the range start..end of lhs.
+///
+/// Indexes are counted in unicode scalar values (chars), not in bytes. Negative indexes count from the end of the
+/// string. Indexes out of range are clamped to the bounds of the string. If end is missing, the range extends to the
+/// end of the string.
+pub type StringSliceFunction = fn(String, i64, Option<i64>) -> String;
+
+pub const FN_SUBSTRING: StringSliceFunction = |lhs, start, end| {
+    let (start, end) = byte_range(lhs.as_str(), start, end);
+    String::from(&lhs[start..end])
+};
+pub const FN_REMOVE_RANGE: StringSliceFunction = |lhs, start, end| {
+    let (start, end) = byte_range(lhs.as_str(), start, end);
+    format!("{}{}", &lhs[..start], &lhs[end..])
+};
+
+lazy_static! {
+    pub static ref STRING_SLICES: HashMap<&'static str, StringSliceFunction> =
+        vec![("substring", FN_SUBSTRING), ("remove_range", FN_REMOVE_RANGE)].into_iter().collect();
+}
+
+/// Resolves a char index which may be negative (counting from the end) and clamps it to 0..=len.
+pub fn resolve_char_index(len: usize, index: i64) -> usize {
+    if index < 0 {
+        len.saturating_sub(index.unsigned_abs() as usize)
+    } else {
+        (index as usize).min(len)
+    }
+}
+
+/// Returns the byte offset of the char with the given index. The result is always on a char boundary.
+pub fn byte_offset(s: &str, char_index: usize) -> usize {
+    s.char_indices().nth(char_index).map(|(offset, _)| offset).unwrap_or_else(|| s.len())
+}
+
+/// Resolves the char range start..end to a byte range of the given string. The start is never greater than the end.
+pub fn byte_range(s: &str, start: i64, end: Option<i64>) -> (usize, usize) {
+    let len = s.chars().count();
+    let start = resolve_char_index(len, start);
+    let end = end.map(|end| resolve_char_index(len, end)).unwrap_or(len).max(start);
+    (byte_offset(s, start), byte_offset(s, end))
+}
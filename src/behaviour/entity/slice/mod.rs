@@ -0,0 +1,8 @@
+pub use function::StringSliceFunction;
+pub use function::STRING_SLICES;
+pub use string_slice::StringSlice;
+pub use string_slice_properties::StringSliceProperties;
+
+pub mod function;
+pub mod string_slice;
+pub mod string_slice_properties;
@@ -0,0 +1,169 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::slice::string_slice_properties::StringSliceProperties;
+use crate::behaviour::entity::slice::StringSliceFunction;
+use crate::behaviour::entity::value::to_index;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+#[derive(Debug, Copy, Clone)]
+pub enum StringSlicePosition {
+    LHS,
+    START,
+    END,
+}
+
+pub type StringSliceExpressionValue = (StringSlicePosition, Value);
+
+/// The state of the string input and the numeric inputs of a string slice.
+#[derive(Debug, Clone)]
+pub struct StringSliceExpression {
+    pub lhs: String,
+    pub start: i64,
+    pub end: Option<i64>,
+}
+
+impl StringSliceExpression {
+    /// Initializes the expression with the current values of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance) -> Self {
+        StringSliceExpression {
+            lhs: e
+                .get(StringSliceProperties::LHS.as_ref())
+                .and_then(|v| v.as_str().map(String::from))
+                .unwrap_or_default(),
+            start: e.get(StringSliceProperties::START.as_ref()).and_then(|v| to_index(&v)).unwrap_or_default(),
+            end: e.get(StringSliceProperties::END.as_ref()).and_then(|v| to_index(&v)),
+        }
+    }
+
+    pub fn set(self, position: StringSlicePosition, value: &Value) -> Self {
+        match position {
+            StringSlicePosition::LHS => StringSliceExpression {
+                lhs: value.as_str().map(String::from).unwrap_or_default(),
+                ..self
+            },
+            StringSlicePosition::START => StringSliceExpression {
+                start: to_index(value).unwrap_or_default(),
+                ..self
+            },
+            StringSlicePosition::END => StringSliceExpression { end: to_index(value), ..self },
+        }
+    }
+}
+
+/// Generic implementation of string operations with a string input (LHS), two numeric inputs (START, END) and one
+/// result.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringSlice<'a> {
+    pub lhs: RwLock<Stream<'a, StringSliceExpressionValue>>,
+
+    pub start: RwLock<Stream<'a, StringSliceExpressionValue>>,
+
+    pub end: RwLock<Stream<'a, StringSliceExpressionValue>>,
+
+    pub f: StringSliceFunction,
+
+    pub internal_result: RwLock<Stream<'a, String>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringSlice<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringSliceFunction) -> StringSlice<'static> {
+        let lhs = e
+            .properties
+            .get(StringSliceProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringSliceExpressionValue { (StringSlicePosition::LHS, v.clone()) });
+        let start = e
+            .properties
+            .get(StringSliceProperties::START.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringSliceExpressionValue { (StringSlicePosition::START, v.clone()) });
+        let end = e
+            .properties
+            .get(StringSliceProperties::END.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringSliceExpressionValue { (StringSlicePosition::END, v.clone()) });
+
+        let expression = lhs
+            .merge(&start)
+            .merge(&end)
+            .fold(StringSliceExpression::new(&e), |old_state, (o, value)| old_state.set(*o, value));
+
+        // The internal result
+        let internal_result = expression.map(move |e| f(e.lhs.clone(), e.start, e.end));
+
+        let handle_id = e.properties.get(StringSliceProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_slice = StringSlice {
+            lhs: RwLock::new(lhs),
+            start: RwLock::new(start),
+            end: RwLock::new(end),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_slice.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string slice: {}", v);
+                e.set(StringSliceProperties::RESULT.to_string(), json!(*v));
+            },
+            handle_id,
+        );
+
+        string_slice
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringSlice<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string slice {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringSlice<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringSliceProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringSliceProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringSlice<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string slice");
+        self.disconnect();
+    }
+}
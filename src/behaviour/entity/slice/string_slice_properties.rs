@@ -0,0 +1,53 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringSliceProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "start")]
+    START,
+    /// If the end is not a number, the range extends to the end of the string.
+    #[strum(serialize = "end")]
+    END,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringSliceProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringSliceProperties::LHS => json!(""),
+            StringSliceProperties::START => json!(0),
+            StringSliceProperties::END => Value::Null,
+            StringSliceProperties::RESULT => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringSliceProperties::LHS),
+            NamedProperty::from(StringSliceProperties::START),
+            NamedProperty::from(StringSliceProperties::END),
+            NamedProperty::from(StringSliceProperties::RESULT),
+        ]
+    }
+}
+
+impl From<StringSliceProperties> for NamedProperty {
+    fn from(p: StringSliceProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringSliceProperties> for String {
+    fn from(p: StringSliceProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -0,0 +1,20 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use serde_json::Value;
+
+/// Serializes a value of any type. The second argument enables pretty printing.
+pub type StringStringifyFunction = fn(Value, bool) -> String;
+
+/// Serializes the value as JSON. Unlike to_string, strings are quoted and escaped.
+pub const FN_JSON_STRINGIFY: StringStringifyFunction = |lhs, pretty| {
+    if pretty {
+        serde_json::to_string_pretty(&lhs).unwrap_or_default()
+    } else {
+        lhs.to_string()
+    }
+};
+
+lazy_static! {
+    pub static ref STRING_STRINGIFIES: HashMap<&'static str, StringStringifyFunction> = vec![("json_stringify", FN_JSON_STRINGIFY)].into_iter().collect();
+}
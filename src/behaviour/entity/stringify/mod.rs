@@ -0,0 +1,8 @@
+pub use function::StringStringifyFunction;
+pub use function::STRING_STRINGIFIES;
+pub use string_stringify::StringStringify;
+pub use string_stringify_properties::StringStringifyProperties;
+
+pub mod function;
+pub mod string_stringify;
+pub mod string_stringify_properties;
@@ -0,0 +1,122 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::stringify::string_stringify_properties::StringStringifyProperties;
+use crate::behaviour::entity::stringify::StringStringifyFunction;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::expression::{Expression, ExpressionValue, OperatorPosition};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+pub type StringStringifyExpressionValue = ExpressionValue<Value>;
+
+/// Generic implementation of value serialization with two inputs (LHS, PRETTY) and one result.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringStringify<'a> {
+    pub lhs: RwLock<Stream<'a, StringStringifyExpressionValue>>,
+
+    pub pretty: RwLock<Stream<'a, StringStringifyExpressionValue>>,
+
+    pub f: StringStringifyFunction,
+
+    pub internal_result: RwLock<Stream<'a, String>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringStringify<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringStringifyFunction) -> StringStringify<'static> {
+        let lhs = e
+            .properties
+            .get(StringStringifyProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringStringifyExpressionValue { (OperatorPosition::LHS, v.clone()) });
+        let pretty = e
+            .properties
+            .get(StringStringifyProperties::PRETTY.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringStringifyExpressionValue { (OperatorPosition::RHS, v.clone()) });
+
+        let expression = lhs.merge(&pretty).fold(
+            Expression::new(
+                e.get(StringStringifyProperties::LHS.as_ref())
+                    .unwrap_or_else(|| StringStringifyProperties::LHS.default_value()),
+                e.get(StringStringifyProperties::PRETTY.as_ref())
+                    .unwrap_or_else(|| StringStringifyProperties::PRETTY.default_value()),
+            ),
+            |old_state, (o, value)| match *o {
+                OperatorPosition::LHS => old_state.lhs(value.clone()),
+                OperatorPosition::RHS => old_state.rhs(value.clone()),
+            },
+        );
+
+        // The internal result
+        let internal_result = expression.map(move |e| f(e.lhs.clone(), e.rhs.as_bool().unwrap_or_default()));
+
+        let handle_id = e.properties.get(StringStringifyProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_stringify = StringStringify {
+            lhs: RwLock::new(lhs),
+            pretty: RwLock::new(pretty),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_stringify.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string stringify: {}", v);
+                e.set(StringStringifyProperties::RESULT.to_string(), json!(*v));
+            },
+            handle_id,
+        );
+
+        string_stringify
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringStringify<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string stringify {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringStringify<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringStringifyProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringStringifyProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringStringify<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string stringify");
+        self.disconnect();
+    }
+}
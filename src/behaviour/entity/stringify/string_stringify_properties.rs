@@ -0,0 +1,48 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringStringifyProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "pretty")]
+    PRETTY,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringStringifyProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringStringifyProperties::LHS => Value::Null,
+            StringStringifyProperties::PRETTY => json!(false),
+            StringStringifyProperties::RESULT => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringStringifyProperties::LHS),
+            NamedProperty::from(StringStringifyProperties::PRETTY),
+            NamedProperty::from(StringStringifyProperties::RESULT),
+        ]
+    }
+}
+
+impl From<StringStringifyProperties> for NamedProperty {
+    fn from(p: StringStringifyProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringStringifyProperties> for String {
+    fn from(p: StringStringifyProperties) -> Self {
+        p.to_string()
+    }
+}
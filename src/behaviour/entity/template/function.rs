@@ -0,0 +1,107 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::io;
+use std::io::Write;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+
+use lazy_static::lazy_static;
+use serde_json::Value;
+use tera::{Context, Tera};
+
+/// Renders the template with the given context object. The rendered result must not exceed the given maximum length.
+pub type StringTemplateFunction = fn(String, Value, usize) -> Result<String, String>;
+
+/// The name of the template in the tera instance which renders it.
+const TEMPLATE_NAME: &str = "template";
+
+/// Renders the template using the tera template engine (https://tera.netlify.app/docs/#templates).
+///
+/// The function `get_env` is disabled. The output is written into a buffer which refuses to grow beyond the maximum
+/// result length, so rendering stops as soon as the limit is reached. All calls of the function `range` during a
+/// render share a budget of elements of the same size, which limits the number of iterations of (nested) loops over
+/// ranges. Loops over the context and strings built with `~` or `set_global` are not limited.
+pub const FN_TEMPLATE: StringTemplateFunction = |template, context, max_result_length| {
+    let context = Context::from_value(context).map_err(|e| error_message(&e))?;
+    let mut tera = Tera::default();
+    tera.register_function("get_env", |_: &HashMap<String, Value>| Err(tera::Error::msg("Function `get_env` is not available")));
+    let range_budget = Arc::new(AtomicUsize::new(max_result_length));
+    tera.register_function("range", move |args: &HashMap<String, Value>| range(args, range_budget.as_ref()));
+    tera.add_raw_template(TEMPLATE_NAME, template.as_str()).map_err(|e| error_message(&e))?;
+    let mut output = LimitedBuffer {
+        buffer: Vec::new(),
+        max_length: max_result_length,
+    };
+    tera.render_to(TEMPLATE_NAME, &context, &mut output).map_err(|e| error_message(&e))?;
+    String::from_utf8(output.buffer).map_err(|e| e.to_string())
+};
+
+lazy_static! {
+    pub static ref STRING_TEMPLATES: HashMap<&'static str, StringTemplateFunction> = vec![("template", FN_TEMPLATE)].into_iter().collect();
+}
+
+/// Tera wraps the actual cause of an error, so the messages of all sources are concatenated.
+fn error_message(error: &tera::Error) -> String {
+    let mut message = error.to_string();
+    let mut source = error.source();
+    while let Some(cause) = source {
+        message = format!("{}: {}", message, cause);
+        source = cause.source();
+    }
+    message
+}
+
+/// Replacement for the builtin `range` function which refuses a step of zero and ranges with more elements than left
+/// in the budget. The elements of each range are subtracted from the budget.
+fn range(args: &HashMap<String, Value>, budget: &AtomicUsize) -> tera::Result<Value> {
+    let start = range_argument(args, "start")?.unwrap_or(0);
+    let step_by = range_argument(args, "step_by")?.unwrap_or(1);
+    let end = range_argument(args, "end")?.ok_or_else(|| tera::Error::msg("Function `range` was called without a `end` argument"))?;
+    if start > end {
+        return Err(tera::Error::msg("Function `range` was called with a `start` argument greater than the `end` one"));
+    }
+    if step_by == 0 {
+        return Err(tera::Error::msg("Function `range` was called with a `step_by` argument of 0"));
+    }
+    let length = (end - start) / step_by + usize::from((end - start) % step_by != 0);
+    budget
+        .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |remaining| remaining.checked_sub(length))
+        .map_err(|remaining| {
+            tera::Error::msg(format!("Function `range` would create {} elements but only {} are left for this template", length, remaining))
+        })?;
+    Ok(Value::from((start..end).step_by(step_by).collect::<Vec<usize>>()))
+}
+
+fn range_argument(args: &HashMap<String, Value>, name: &str) -> tera::Result<Option<usize>> {
+    match args.get(name) {
+        Some(value) => value
+            .as_u64()
+            .and_then(|value| usize::try_from(value).ok())
+            .map(Some)
+            .ok_or_else(|| tera::Error::msg(format!("Function `range` received {}={} but `{}` can only be a number", name, value, name))),
+        None => Ok(None),
+    }
+}
+
+/// Collects the rendered output and fails as soon as it would exceed the maximum length.
+struct LimitedBuffer {
+    buffer: Vec<u8>,
+    max_length: usize,
+}
+
+impl Write for LimitedBuffer {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.buffer.len() + buf.len() > self.max_length {
+            return Err(io::Error::new(
+                io::ErrorKind::OutOfMemory,
+                format!("The result exceeds the maximum result length of {} bytes", self.max_length),
+            ));
+        }
+        self.buffer.extend_from_slice(buf);
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
@@ -0,0 +1,8 @@
+pub use function::StringTemplateFunction;
+pub use function::STRING_TEMPLATES;
+pub use string_template::StringTemplate;
+pub use string_template_properties::StringTemplateProperties;
+
+pub mod function;
+pub mod string_template;
+pub mod string_template_properties;
@@ -0,0 +1,124 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::template::string_template_properties::StringTemplateProperties;
+use crate::behaviour::entity::template::StringTemplateFunction;
+use crate::config::get_config;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::expression::{Expression, ExpressionValue, OperatorPosition};
+use crate::reactive::entity::Disconnectable;
+
+pub type StringTemplateExpressionValue = ExpressionValue<Value>;
+
+/// Generic implementation of template rendering with two inputs (TEMPLATE, CONTEXT) and one result. Errors during
+/// rendering are reported on the error output.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringTemplate<'a> {
+    pub template: RwLock<Stream<'a, StringTemplateExpressionValue>>,
+
+    pub context: RwLock<Stream<'a, StringTemplateExpressionValue>>,
+
+    pub f: StringTemplateFunction,
+
+    pub internal_result: RwLock<Stream<'a, Result<String, String>>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringTemplate<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringTemplateFunction) -> StringTemplate<'static> {
+        let template = e
+            .properties
+            .get(StringTemplateProperties::TEMPLATE.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringTemplateExpressionValue { (OperatorPosition::LHS, v.clone()) });
+        let context = e
+            .properties
+            .get(StringTemplateProperties::CONTEXT.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringTemplateExpressionValue { (OperatorPosition::RHS, v.clone()) });
+
+        let expression = template.merge(&context).fold(
+            Expression::new(
+                e.get(StringTemplateProperties::TEMPLATE.as_ref())
+                    .unwrap_or_else(|| StringTemplateProperties::TEMPLATE.default_value()),
+                e.get(StringTemplateProperties::CONTEXT.as_ref())
+                    .unwrap_or_else(|| StringTemplateProperties::CONTEXT.default_value()),
+            ),
+            |old_state, (o, value)| match *o {
+                OperatorPosition::LHS => old_state.lhs(value.clone()),
+                OperatorPosition::RHS => old_state.rhs(value.clone()),
+            },
+        );
+
+        // The internal result
+        let max_result_length = get_config().max_result_length;
+        let internal_result = expression.map(move |e| match e.lhs.as_str() {
+            Some(template) => f(String::from(template), e.rhs.clone(), max_result_length),
+            None => Err(String::from("The template is not a string")),
+        });
+
+        let handle_id = e.properties.get(StringTemplateProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_template = StringTemplate {
+            template: RwLock::new(template),
+            context: RwLock::new(context),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_template.internal_result.read().unwrap().observe_with_handle(
+            move |v| match v {
+                Ok(result) => {
+                    debug!("Setting result of string template: {}", result);
+                    e.set(StringTemplateProperties::ERROR.to_string(), json!(""));
+                    e.set(StringTemplateProperties::RESULT.to_string(), json!(result));
+                }
+                Err(error) => {
+                    debug!("Setting error of string template: {}", error);
+                    e.set(StringTemplateProperties::ERROR.to_string(), json!(error));
+                }
+            },
+            handle_id,
+        );
+
+        string_template
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringTemplate<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string template {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringTemplate<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string template");
+        self.disconnect();
+    }
+}
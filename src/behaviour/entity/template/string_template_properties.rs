@@ -0,0 +1,52 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringTemplateProperties {
+    #[strum(serialize = "template")]
+    TEMPLATE,
+    #[strum(serialize = "context")]
+    CONTEXT,
+    #[strum(serialize = "result")]
+    RESULT,
+    #[strum(serialize = "error")]
+    ERROR,
+}
+
+impl StringTemplateProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringTemplateProperties::TEMPLATE => json!(""),
+            StringTemplateProperties::CONTEXT => json!({}),
+            StringTemplateProperties::RESULT => json!(""),
+            StringTemplateProperties::ERROR => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringTemplateProperties::TEMPLATE),
+            NamedProperty::from(StringTemplateProperties::CONTEXT),
+            NamedProperty::from(StringTemplateProperties::RESULT),
+            NamedProperty::from(StringTemplateProperties::ERROR),
+        ]
+    }
+}
+
+impl From<StringTemplateProperties> for NamedProperty {
+    fn from(p: StringTemplateProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringTemplateProperties> for String {
+    fn from(p: StringTemplateProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -0,0 +1,75 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use regex::Regex;
+use unicode_segmentation::UnicodeSegmentation;
+
+/// The rules of a string validator.
+#[derive(Debug, Clone)]
+pub struct ValidationRules {
+    /// The minimum length in grapheme clusters. 0 means no minimum.
+    pub min_length: usize,
+    /// The maximum length in grapheme clusters. 0 means unlimited.
+    pub max_length: usize,
+    /// The allowed characters. Ranges like a-z are supported, a leading or trailing - is literal. Empty allows any
+    /// character.
+    pub allowed_chars: String,
+    /// The compiled pattern or the error message if the pattern is invalid. None if there is no pattern.
+    pub regex: Option<Result<Regex, String>>,
+}
+
+/// Validates lhs against the rules and returns a message for every violated rule.
+pub type StringValidatorFunction = fn(&str, &ValidationRules) -> Vec<String>;
+
+pub const FN_STRING_VALIDATOR: StringValidatorFunction = |lhs, rules| {
+    let mut errors = Vec::new();
+    let length = lhs.graphemes(true).count();
+    if rules.min_length > 0 && length < rules.min_length {
+        errors.push(format!("Must be at least {} characters long", rules.min_length));
+    }
+    if rules.max_length > 0 && length > rules.max_length {
+        errors.push(format!("Must be at most {} characters long", rules.max_length));
+    }
+    if !rules.allowed_chars.is_empty() {
+        let mut invalid_chars: Vec<char> = Vec::new();
+        for c in lhs.chars() {
+            if !is_allowed(c, rules.allowed_chars.as_str()) && !invalid_chars.contains(&c) {
+                invalid_chars.push(c);
+            }
+        }
+        if !invalid_chars.is_empty() {
+            let invalid_chars: Vec<String> = invalid_chars.iter().map(|c| format!("'{}'", c)).collect();
+            errors.push(format!("Contains characters which are not allowed: {}", invalid_chars.join(", ")));
+        }
+    }
+    match &rules.regex {
+        Some(Ok(regex)) if !regex.is_match(lhs) => errors.push(format!("Must match the pattern {}", regex.as_str())),
+        Some(Err(error)) => errors.push(format!("Invalid pattern: {}", error)),
+        _ => {}
+    }
+    errors
+};
+
+lazy_static! {
+    pub static ref STRING_VALIDATORS: HashMap<&'static str, StringValidatorFunction> = vec![("string_validator", FN_STRING_VALIDATOR)].into_iter().collect();
+}
+
+/// Returns true if the character is contained in the allowed characters or in one of the ranges.
+fn is_allowed(c: char, allowed_chars: &str) -> bool {
+    let allowed_chars: Vec<char> = allowed_chars.chars().collect();
+    let mut i = 0;
+    while i < allowed_chars.len() {
+        if i + 2 < allowed_chars.len() && allowed_chars[i + 1] == '-' {
+            if (allowed_chars[i]..=allowed_chars[i + 2]).contains(&c) {
+                return true;
+            }
+            i += 3;
+        } else {
+            if allowed_chars[i] == c {
+                return true;
+            }
+            i += 1;
+        }
+    }
+    false
+}
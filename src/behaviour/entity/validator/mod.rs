@@ -0,0 +1,9 @@
+pub use function::StringValidatorFunction;
+pub use function::ValidationRules;
+pub use function::STRING_VALIDATORS;
+pub use string_validator::StringValidator;
+pub use string_validator_properties::StringValidatorProperties;
+
+pub mod function;
+pub mod string_validator;
+pub mod string_validator_properties;
@@ -0,0 +1,240 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use regex::Regex;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::validator::string_validator_properties::StringValidatorProperties;
+use crate::behaviour::entity::validator::{StringValidatorFunction, ValidationRules};
+use crate::behaviour::entity::value::to_size;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::Disconnectable;
+
+#[allow(non_camel_case_types)]
+#[derive(Debug, Copy, Clone)]
+pub enum StringValidatorPosition {
+    LHS,
+    MIN_LENGTH,
+    MAX_LENGTH,
+    ALLOWED_CHARS,
+    PATTERN,
+}
+
+pub type StringValidatorExpressionValue = (StringValidatorPosition, Value);
+
+/// The state of the inputs of a string validator.
+///
+/// The compiled regular expression is part of the rules and is only recompiled if the pattern changes.
+#[derive(Debug, Clone)]
+pub struct StringValidatorExpression {
+    pub lhs: String,
+    pub pattern: String,
+    pub rules: ValidationRules,
+}
+
+impl StringValidatorExpression {
+    /// Initializes the expression with the current values of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance) -> Self {
+        let get = |property: StringValidatorProperties| e.get(property.as_ref()).unwrap_or_else(|| property.default_value());
+        let pattern = to_string(&get(StringValidatorProperties::PATTERN));
+        StringValidatorExpression {
+            lhs: to_string(&get(StringValidatorProperties::LHS)),
+            rules: ValidationRules {
+                min_length: to_size(&get(StringValidatorProperties::MIN_LENGTH)),
+                max_length: to_size(&get(StringValidatorProperties::MAX_LENGTH)),
+                allowed_chars: to_string(&get(StringValidatorProperties::ALLOWED_CHARS)),
+                regex: compile(pattern.as_str()),
+            },
+            pattern,
+        }
+    }
+
+    pub fn set(self, position: StringValidatorPosition, value: &Value) -> Self {
+        let rules = self.rules;
+        match position {
+            StringValidatorPosition::LHS => StringValidatorExpression {
+                lhs: to_string(value),
+                rules,
+                ..self
+            },
+            StringValidatorPosition::MIN_LENGTH => StringValidatorExpression {
+                rules: ValidationRules {
+                    min_length: to_size(value),
+                    ..rules
+                },
+                ..self
+            },
+            StringValidatorPosition::MAX_LENGTH => StringValidatorExpression {
+                rules: ValidationRules {
+                    max_length: to_size(value),
+                    ..rules
+                },
+                ..self
+            },
+            StringValidatorPosition::ALLOWED_CHARS => StringValidatorExpression {
+                rules: ValidationRules {
+                    allowed_chars: to_string(value),
+                    ..rules
+                },
+                ..self
+            },
+            StringValidatorPosition::PATTERN => {
+                let pattern = to_string(value);
+                if pattern == self.pattern {
+                    return StringValidatorExpression { rules, ..self };
+                }
+                StringValidatorExpression {
+                    rules: ValidationRules {
+                        regex: compile(pattern.as_str()),
+                        ..rules
+                    },
+                    pattern,
+                    ..self
+                }
+            }
+        }
+    }
+}
+
+fn to_string(value: &Value) -> String {
+    value.as_str().map(String::from).unwrap_or_default()
+}
+
+/// An empty pattern disables the rule.
+fn compile(pattern: &str) -> Option<Result<Regex, String>> {
+    if pattern.is_empty() {
+        return None;
+    }
+    debug!("Compiling regular expression {}", pattern);
+    Some(Regex::new(pattern).map_err(|e| e.to_string()))
+}
+
+/// Validates a string input (LHS) against several rules (MIN_LENGTH, MAX_LENGTH, ALLOWED_CHARS, PATTERN).
+///
+/// There are two outputs: ERRORS lists a message for every violated rule and VALID is true if no rule is violated.
+/// ERRORS is updated before VALID, so observers of VALID always see the matching errors.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringValidator<'a> {
+    pub lhs: RwLock<Stream<'a, StringValidatorExpressionValue>>,
+
+    pub min_length: RwLock<Stream<'a, StringValidatorExpressionValue>>,
+
+    pub max_length: RwLock<Stream<'a, StringValidatorExpressionValue>>,
+
+    pub allowed_chars: RwLock<Stream<'a, StringValidatorExpressionValue>>,
+
+    pub pattern: RwLock<Stream<'a, StringValidatorExpressionValue>>,
+
+    pub f: StringValidatorFunction,
+
+    pub internal_result: RwLock<Stream<'a, Vec<String>>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringValidator<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringValidatorFunction) -> StringValidator<'static> {
+        let lhs = e
+            .properties
+            .get(StringValidatorProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringValidatorExpressionValue { (StringValidatorPosition::LHS, v.clone()) });
+        let min_length = e
+            .properties
+            .get(StringValidatorProperties::MIN_LENGTH.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringValidatorExpressionValue { (StringValidatorPosition::MIN_LENGTH, v.clone()) });
+        let max_length = e
+            .properties
+            .get(StringValidatorProperties::MAX_LENGTH.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringValidatorExpressionValue { (StringValidatorPosition::MAX_LENGTH, v.clone()) });
+        let allowed_chars = e
+            .properties
+            .get(StringValidatorProperties::ALLOWED_CHARS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringValidatorExpressionValue { (StringValidatorPosition::ALLOWED_CHARS, v.clone()) });
+        let pattern = e
+            .properties
+            .get(StringValidatorProperties::PATTERN.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringValidatorExpressionValue { (StringValidatorPosition::PATTERN, v.clone()) });
+
+        let expression = lhs
+            .merge(&min_length)
+            .merge(&max_length)
+            .merge(&allowed_chars)
+            .merge(&pattern)
+            .fold(StringValidatorExpression::new(&e), |old_state, (o, value)| old_state.set(*o, value));
+
+        // The internal result
+        let internal_result = expression.map(move |e| f(e.lhs.as_str(), &e.rules));
+
+        let handle_id = e.properties.get(StringValidatorProperties::VALID.as_ref()).unwrap().id.as_u128();
+
+        let string_validator = StringValidator {
+            lhs: RwLock::new(lhs),
+            min_length: RwLock::new(min_length),
+            max_length: RwLock::new(max_length),
+            allowed_chars: RwLock::new(allowed_chars),
+            pattern: RwLock::new(pattern),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the streams of the errors and the valid property
+        string_validator.internal_result.read().unwrap().observe_with_handle(
+            move |errors| {
+                debug!("Setting result of string validator: {:?}", errors);
+                e.set(StringValidatorProperties::ERRORS.to_string(), json!(errors));
+                e.set(StringValidatorProperties::VALID.to_string(), json!(errors.is_empty()));
+            },
+            handle_id,
+        );
+
+        string_validator
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringValidator<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string validator {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringValidator<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string validator");
+        self.disconnect();
+    }
+}
@@ -0,0 +1,68 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringValidatorProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    /// A min_length of 0 or less means no minimum
+    #[strum(serialize = "min_length")]
+    MIN_LENGTH,
+    /// A max_length of 0 or less means unlimited
+    #[strum(serialize = "max_length")]
+    MAX_LENGTH,
+    /// An empty string allows any character
+    #[strum(serialize = "allowed_chars")]
+    ALLOWED_CHARS,
+    /// An empty pattern matches any string
+    #[strum(serialize = "pattern")]
+    PATTERN,
+    #[strum(serialize = "valid")]
+    VALID,
+    #[strum(serialize = "errors")]
+    ERRORS,
+}
+
+impl StringValidatorProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringValidatorProperties::LHS => json!(""),
+            StringValidatorProperties::MIN_LENGTH => json!(0),
+            StringValidatorProperties::MAX_LENGTH => json!(0),
+            StringValidatorProperties::ALLOWED_CHARS => json!(""),
+            StringValidatorProperties::PATTERN => json!(""),
+            StringValidatorProperties::VALID => json!(true),
+            StringValidatorProperties::ERRORS => json!([]),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringValidatorProperties::LHS),
+            NamedProperty::from(StringValidatorProperties::MIN_LENGTH),
+            NamedProperty::from(StringValidatorProperties::MAX_LENGTH),
+            NamedProperty::from(StringValidatorProperties::ALLOWED_CHARS),
+            NamedProperty::from(StringValidatorProperties::PATTERN),
+            NamedProperty::from(StringValidatorProperties::VALID),
+            NamedProperty::from(StringValidatorProperties::ERRORS),
+        ]
+    }
+}
+
+impl From<StringValidatorProperties> for NamedProperty {
+    fn from(p: StringValidatorProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringValidatorProperties> for String {
+    fn from(p: StringValidatorProperties) -> Self {
+        p.to_string()
+    }
+}
@@ -0,0 +1,12 @@
+use serde_json::Value;
+
+/// Converts a numeric property value into an index. Floating point numbers are truncated.
+pub fn to_index(value: &Value) -> Option<i64> {
+    value.as_i64().or_else(|| value.as_f64().map(|v| v.trunc() as i64))
+}
+
+/// Converts a numeric property value into a size. Values which are not a number and negative values are treated as
+/// zero.
+pub fn to_size(value: &Value) -> usize {
+    to_index(value).unwrap_or_default().max(0) as usize
+}
@@ -0,0 +1,17 @@
+use std::collections::HashMap;
+
+use lazy_static::lazy_static;
+use serde_json::Value;
+
+use crate::behaviour::entity::join::function::value_to_string;
+
+/// Converts a value of any type into a string.
+pub type StringValueOperationFunction = fn(Value) -> String;
+
+/// Strings are returned as they are, null becomes an empty string. Numbers and booleans are converted into their
+/// textual representation, arrays and objects into JSON.
+pub const FN_TO_STRING: StringValueOperationFunction = |lhs| value_to_string(&lhs);
+
+lazy_static! {
+    pub static ref STRING_VALUE_OPERATIONS: HashMap<&'static str, StringValueOperationFunction> = vec![("to_string", FN_TO_STRING)].into_iter().collect();
+}
@@ -0,0 +1,8 @@
+pub use function::StringValueOperationFunction;
+pub use function::STRING_VALUE_OPERATIONS;
+pub use string_value_operation::StringValueOperation;
+pub use string_value_operation_properties::StringValueOperationProperties;
+
+pub mod function;
+pub mod string_value_operation;
+pub mod string_value_operation_properties;
@@ -0,0 +1,89 @@
+use std::convert::AsRef;
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::value_operation::string_value_operation_properties::StringValueOperationProperties;
+use crate::behaviour::entity::value_operation::StringValueOperationFunction;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::operation::Operation;
+use crate::reactive::entity::Disconnectable;
+
+/// Generic implementation of operations with one input of any type and one string result.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringValueOperation<'a> {
+    pub f: StringValueOperationFunction,
+
+    pub internal_result: RwLock<Stream<'a, String>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringValueOperation<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringValueOperationFunction) -> StringValueOperation<'static> {
+        let handle_id = e.properties.get(StringValueOperationProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let internal_result = e
+            .properties
+            .get(StringValueOperationProperties::LHS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(move |v| f(v.clone()));
+        let string_value_operation = StringValueOperation {
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_value_operation.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string value operation: {}", v);
+                e.set(StringValueOperationProperties::RESULT.to_string(), json!(*v));
+            },
+            handle_id,
+        );
+
+        string_value_operation
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringValueOperation<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string value operation {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+impl Operation for StringValueOperation<'_> {
+    fn lhs(&self, value: Value) {
+        self.entity.set(StringValueOperationProperties::LHS.as_ref(), value);
+    }
+
+    fn result(&self) -> Value {
+        self.entity.get(StringValueOperationProperties::RESULT.as_ref()).unwrap()
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringValueOperation<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string value operation");
+        self.disconnect();
+    }
+}
@@ -0,0 +1,44 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringValueOperationProperties {
+    #[strum(serialize = "lhs")]
+    LHS,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringValueOperationProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringValueOperationProperties::LHS => Value::Null,
+            StringValueOperationProperties::RESULT => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringValueOperationProperties::LHS),
+            NamedProperty::from(StringValueOperationProperties::RESULT),
+        ]
+    }
+}
+
+impl From<StringValueOperationProperties> for NamedProperty {
+    fn from(p: StringValueOperationProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringValueOperationProperties> for String {
+    fn from(p: StringValueOperationProperties) -> Self {
+        p.to_string()
+    }
+}
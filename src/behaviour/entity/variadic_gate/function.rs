@@ -0,0 +1,12 @@
+use lazy_static::lazy_static;
+use std::collections::HashMap;
+
+/// Combines the inputs (in index order) using the separator.
+pub type StringVariadicGateFunction = fn(Vec<String>, String) -> String;
+
+pub const FN_VARIADIC_CONCAT: StringVariadicGateFunction = |inputs, separator| inputs.join(separator.as_str());
+
+lazy_static! {
+    pub static ref STRING_VARIADIC_GATES: HashMap<&'static str, StringVariadicGateFunction> =
+        vec![("variadic_concat", FN_VARIADIC_CONCAT)].into_iter().collect();
+}
@@ -0,0 +1,8 @@
+pub use function::StringVariadicGateFunction;
+pub use function::STRING_VARIADIC_GATES;
+pub use string_variadic_gate::StringVariadicGate;
+pub use string_variadic_gate_properties::StringVariadicGateProperties;
+
+pub mod function;
+pub mod string_variadic_gate;
+pub mod string_variadic_gate_properties;
@@ -0,0 +1,198 @@
+use std::sync::{Arc, RwLock};
+
+use log::debug;
+use serde_json::{json, Value};
+
+use crate::behaviour::entity::join::function::value_to_string;
+use crate::behaviour::entity::variadic_gate::string_variadic_gate_properties::StringVariadicGateProperties;
+use crate::behaviour::entity::variadic_gate::StringVariadicGateFunction;
+use crate::frp::Stream;
+use crate::model::{PropertyInstanceGetter, PropertyInstanceSetter, ReactiveEntityInstance};
+use crate::reactive::entity::Disconnectable;
+
+#[derive(Debug, Copy, Clone)]
+pub enum StringVariadicGatePosition {
+    /// The position of the input in the list of inputs, ordered by the index of the input property.
+    INPUT(usize),
+    INPUTS,
+    SEPARATOR,
+}
+
+pub type StringVariadicGateExpressionValue = (StringVariadicGatePosition, Value);
+
+/// The state of the inputs of a string variadic gate.
+#[derive(Debug, Clone)]
+pub struct StringVariadicGateExpression {
+    /// The values of the numbered input properties.
+    pub inputs: Vec<String>,
+    /// The elements of the inputs array.
+    pub elements: Vec<String>,
+    pub separator: String,
+}
+
+impl StringVariadicGateExpression {
+    /// Initializes the expression with the current values of the given input properties of the entity instance.
+    pub fn new(e: &ReactiveEntityInstance, input_names: &[String]) -> Self {
+        StringVariadicGateExpression {
+            inputs: input_names
+                .iter()
+                .map(|name| e.get(name.as_str()).map(|v| value_to_string(&v)).unwrap_or_default())
+                .collect(),
+            elements: e
+                .get(StringVariadicGateProperties::INPUTS.as_ref())
+                .map(|v| to_elements(&v))
+                .unwrap_or_default(),
+            separator: e
+                .get(StringVariadicGateProperties::SEPARATOR.as_ref())
+                .map(|v| value_to_string(&v))
+                .unwrap_or_default(),
+        }
+    }
+
+    pub fn set(mut self, position: StringVariadicGatePosition, value: &Value) -> Self {
+        match position {
+            StringVariadicGatePosition::INPUT(position) => {
+                if let Some(input) = self.inputs.get_mut(position) {
+                    *input = value_to_string(value);
+                }
+            }
+            StringVariadicGatePosition::INPUTS => self.elements = to_elements(value),
+            StringVariadicGatePosition::SEPARATOR => self.separator = value_to_string(value),
+        }
+        self
+    }
+
+    /// Returns the numbered inputs in index order followed by the elements of the inputs array.
+    pub fn all_inputs(&self) -> Vec<String> {
+        self.inputs.iter().chain(self.elements.iter()).cloned().collect()
+    }
+}
+
+/// Converts the elements of an array to strings. Any other value is treated as an empty array.
+fn to_elements(value: &Value) -> Vec<String> {
+    value
+        .as_array()
+        .map(|elements| elements.iter().map(value_to_string).collect())
+        .unwrap_or_default()
+}
+
+/// Generic implementation of string gates with a variable number of inputs (input_0, input_1, ...), an array of
+/// inputs, a separator and one result. Inputs which are not strings are converted using `value_to_string`.
+///
+/// The numbered inputs are the properties of the entity instance at the time the behaviour is created, input properties
+/// which are added later are ignored. Gaps between the indexes don't matter, the numbered inputs are processed in the
+/// order of their indexes, followed by the elements of the inputs array. The number of inputs can be changed at runtime
+/// using the inputs array.
+///
+/// The implementation is realized using reactive streams.
+pub struct StringVariadicGate<'a> {
+    pub inputs: RwLock<Vec<Stream<'a, StringVariadicGateExpressionValue>>>,
+
+    pub input_array: RwLock<Stream<'a, StringVariadicGateExpressionValue>>,
+
+    pub separator: RwLock<Stream<'a, StringVariadicGateExpressionValue>>,
+
+    pub f: StringVariadicGateFunction,
+
+    pub internal_result: RwLock<Stream<'a, String>>,
+
+    pub entity: Arc<ReactiveEntityInstance>,
+
+    pub handle_id: u128,
+}
+
+impl StringVariadicGate<'_> {
+    pub fn new(e: Arc<ReactiveEntityInstance>, f: StringVariadicGateFunction) -> StringVariadicGate<'static> {
+        let mut input_names: Vec<(usize, String)> = e
+            .properties
+            .keys()
+            .filter_map(|name| StringVariadicGateProperties::input_index(name.as_str()).map(|index| (index, name.clone())))
+            .collect();
+        input_names.sort();
+        let input_names: Vec<String> = input_names.into_iter().map(|(_, name)| name).collect();
+        debug!("Inputs of string variadic gate {}: {:?}", e.id, input_names);
+
+        let inputs: Vec<Stream<'static, StringVariadicGateExpressionValue>> = input_names
+            .iter()
+            .enumerate()
+            .map(|(position, name)| {
+                e.properties
+                    .get(name.as_str())
+                    .unwrap()
+                    .stream
+                    .read()
+                    .unwrap()
+                    .map(move |v| -> StringVariadicGateExpressionValue { (StringVariadicGatePosition::INPUT(position), v.clone()) })
+            })
+            .collect();
+        let input_array = e
+            .properties
+            .get(StringVariadicGateProperties::INPUTS.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringVariadicGateExpressionValue { (StringVariadicGatePosition::INPUTS, v.clone()) });
+        let separator = e
+            .properties
+            .get(StringVariadicGateProperties::SEPARATOR.as_ref())
+            .unwrap()
+            .stream
+            .read()
+            .unwrap()
+            .map(|v| -> StringVariadicGateExpressionValue { (StringVariadicGatePosition::SEPARATOR, v.clone()) });
+
+        let expression = inputs
+            .iter()
+            .fold(separator.merge(&input_array), |merged, input| merged.merge(input))
+            .fold(StringVariadicGateExpression::new(&e, &input_names), |old_state, (o, value)| old_state.set(*o, value));
+
+        // The internal result
+        let internal_result = expression.map(move |e| f(e.all_inputs(), e.separator.clone()));
+
+        let handle_id = e.properties.get(StringVariadicGateProperties::RESULT.as_ref()).unwrap().id.as_u128();
+
+        let string_variadic_gate = StringVariadicGate {
+            inputs: RwLock::new(inputs),
+            input_array: RwLock::new(input_array),
+            separator: RwLock::new(separator),
+            f,
+            internal_result: RwLock::new(internal_result),
+            entity: e.clone(),
+            handle_id,
+        };
+
+        // Connect the internal result with the stream of the result property
+        string_variadic_gate.internal_result.read().unwrap().observe_with_handle(
+            move |v| {
+                debug!("Setting result of string variadic gate: {}", v);
+                e.set(StringVariadicGateProperties::RESULT.to_string(), json!(*v));
+            },
+            handle_id,
+        );
+
+        string_variadic_gate
+    }
+
+    /// TODO: extract to trait "Named"
+    /// TODO: unit test
+    pub fn type_name(&self) -> String {
+        self.entity.type_name.clone()
+    }
+}
+
+impl Disconnectable for StringVariadicGate<'_> {
+    /// TODO: Add guard: disconnect only if actually connected
+    fn disconnect(&self) {
+        debug!("Disconnect string variadic gate {} {}", self.type_name(), self.handle_id);
+        self.internal_result.read().unwrap().remove(self.handle_id);
+    }
+}
+
+/// Automatically disconnect streams on destruction
+impl Drop for StringVariadicGate<'_> {
+    fn drop(&mut self) {
+        debug!("Drop string variadic gate");
+        self.disconnect();
+    }
+}
@@ -0,0 +1,56 @@
+use indradb::{Identifier, NamedProperty};
+use serde_json::{json, Value};
+use strum_macros::{AsRefStr, Display, IntoStaticStr};
+
+use crate::reactive::property::NamedProperties;
+
+/// The prefix of the numbered input properties: input_0, input_1, ...
+pub const INPUT_PREFIX: &str = "input_";
+
+#[allow(non_camel_case_types)]
+#[derive(AsRefStr, IntoStaticStr, Display)]
+pub enum StringVariadicGateProperties {
+    #[strum(serialize = "inputs")]
+    INPUTS,
+    #[strum(serialize = "separator")]
+    SEPARATOR,
+    #[strum(serialize = "result")]
+    RESULT,
+}
+
+impl StringVariadicGateProperties {
+    pub fn default_value(&self) -> Value {
+        match self {
+            StringVariadicGateProperties::INPUTS => json!([]),
+            StringVariadicGateProperties::SEPARATOR => json!(""),
+            StringVariadicGateProperties::RESULT => json!(""),
+        }
+    }
+    pub fn properties() -> NamedProperties {
+        vec![
+            NamedProperty::from(StringVariadicGateProperties::INPUTS),
+            NamedProperty::from(StringVariadicGateProperties::SEPARATOR),
+            NamedProperty::from(StringVariadicGateProperties::RESULT),
+        ]
+    }
+
+    /// Returns the index of a numbered input property or None if the property is not an input.
+    pub fn input_index(property_name: &str) -> Option<usize> {
+        property_name.strip_prefix(INPUT_PREFIX).and_then(|index| index.parse::<usize>().ok())
+    }
+}
+
+impl From<StringVariadicGateProperties> for NamedProperty {
+    fn from(p: StringVariadicGateProperties) -> Self {
+        NamedProperty {
+            name: Identifier::new(p.to_string()).unwrap(),
+            value: p.default_value(),
+        }
+    }
+}
+
+impl From<StringVariadicGateProperties> for String {
+    fn from(p: StringVariadicGateProperties) -> Self {
+        p.to_string()
+    }
+}
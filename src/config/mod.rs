@@ -0,0 +1,53 @@
+use lazy_static::lazy_static;
+use log::{debug, warn};
+use serde::Deserialize;
+use std::fs;
+
+/// The location of the configuration file of the string plugin (relative to the working directory).
+pub const CONFIG_PATH: &str = "config/string.toml";
+
+/// The default maximum length of generated strings in bytes (1 MiB).
+pub const DEFAULT_MAX_RESULT_LENGTH: usize = 1024 * 1024;
+
+/// Plugin-wide configuration of the string plugin.
+#[derive(Debug, Clone, Deserialize)]
+#[serde(default)]
+pub struct StringPluginConfig {
+    /// Behaviours which may generate arbitrary large strings (repeat, pad, replace, regex replace, number format and
+    /// template) refuse to produce results longer than this (in bytes).
+    pub max_result_length: usize,
+}
+
+impl Default for StringPluginConfig {
+    fn default() -> Self {
+        StringPluginConfig {
+            max_result_length: DEFAULT_MAX_RESULT_LENGTH,
+        }
+    }
+}
+
+lazy_static! {
+    pub static ref STRING_PLUGIN_CONFIG: StringPluginConfig = load_config();
+}
+
+/// Returns the plugin configuration. The configuration file is read once on first access.
+pub fn get_config() -> &'static StringPluginConfig {
+    &STRING_PLUGIN_CONFIG
+}
+
+/// Reads the configuration file. Falls back to the defaults if the file doesn't exist or is invalid.
+fn load_config() -> StringPluginConfig {
+    match fs::read_to_string(CONFIG_PATH) {
+        Ok(content) => match toml::from_str(content.as_str()) {
+            Ok(config) => config,
+            Err(error) => {
+                warn!("Failed to parse {}, using defaults: {}", CONFIG_PATH, error);
+                StringPluginConfig::default()
+            }
+        },
+        Err(_) => {
+            debug!("No configuration file {} found, using defaults", CONFIG_PATH);
+            StringPluginConfig::default()
+        }
+    }
+}
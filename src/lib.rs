@@ -15,6 +15,7 @@ use crate::plugin::StringPlugin;
 use crate::plugins::{Plugin, PluginError};
 
 pub mod behaviour;
+pub mod config;
 pub mod plugin;
 pub mod provider;
 